@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// On-disk cache of GET response bodies keyed by method + full URL (including query) and a
+/// hash of the token, so one token never reads another's private responses. The directory is
+/// created 0700 and entries are written 0600 (on Unix).
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    ttl: Duration,
+    refresh: bool,
+}
+
+impl ResponseCache {
+    pub fn new(dir: PathBuf, ttl: Duration) -> Self {
+        Self { dir, ttl, refresh: false }
+    }
+
+    /// Skip reads (always go to the network) but still store fresh responses.
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    /// Returns the stored body and its `Link` header, if any.
+    pub(crate) fn get(&self, key: &str) -> Option<(serde_json::Value, Option<String>)> {
+        if self.refresh {
+            return None;
+        }
+        let content = fs::read_to_string(self.path_for(key)).ok()?;
+        let entry: serde_json::Value = serde_json::from_str(&content).ok()?;
+        if entry.get("key").and_then(|k| k.as_str()) != Some(key) {
+            return None;
+        }
+        let stored_at = entry.get("stored_at_ms")?.as_u64()?;
+        let age = now_ms().saturating_sub(stored_at);
+        if u128::from(age) >= self.ttl.as_millis() {
+            return None;
+        }
+        let link = entry.get("link").and_then(|l| l.as_str()).map(str::to_string);
+        Some((entry.get("body")?.clone(), link))
+    }
+
+    // Best effort: a cache that cannot be written just behaves like a miss next time.
+    pub(crate) fn put(&self, key: &str, body: &serde_json::Value, link: Option<&str>) {
+        let entry = serde_json::json!({ "key": key, "stored_at_ms": now_ms(), "body": body, "link": link });
+        if create_private_dir(&self.dir).is_ok() {
+            let _ = write_private(&self.path_for(key), entry.to_string().as_bytes());
+        }
+    }
+
+    /// Cache key for `request` ("METHOD url") made as `token`: the token only enters as a hash.
+    pub(crate) fn key_for(request: &str, token: Option<&str>) -> String {
+        match token {
+            Some(t) => format!("{request} as:{:016x}", fnv1a(t.as_bytes())),
+            None => format!("{request} as:anon"),
+        }
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", fnv1a(key.as_bytes())))
+    }
+}
+
+#[cfg(unix)]
+fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    fs::DirBuilder::new().recursive(true).mode(0o700).create(dir)?;
+    // A directory left by an older version may still be world-readable
+    fs::set_permissions(dir, fs::Permissions::from_mode(0o700))
+}
+
+#[cfg(not(unix))]
+fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(dir)
+}
+
+fn write_private(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // `mode` only applies to new files; tighten entries written before
+        if let Ok(meta) = fs::metadata(path) {
+            if meta.permissions().mode() & 0o077 != 0 {
+                fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+            }
+        }
+    }
+    options.open(path)?.write_all(bytes)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+// Stable across Rust releases, unlike `DefaultHasher`, so cache file names survive upgrades.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325u64, |h, b| (h ^ u64::from(*b)).wrapping_mul(0x100000001b3))
+}
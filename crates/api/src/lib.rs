@@ -1,8 +1,32 @@
+#![allow(clippy::too_many_arguments)]
 use reqwest::header::{HeaderMap, HeaderValue, HeaderName, ACCEPT, AUTHORIZATION, USER_AGENT};
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use url::Url;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// `tracing` target for wire-level request/response events (enabled at DEBUG).
+pub const HTTP_LOG_TARGET: &str = "gh_otco_api::http";
+
+/// REST API version sent as `X-GitHub-Api-Version` unless overridden with `with_api_version`.
+pub const DEFAULT_API_VERSION: &str = "2022-11-28";
+
+/// `User-Agent` sent unless the builder sets another (GitHub rejects requests without one).
+pub const DEFAULT_USER_AGENT: &str = "gh-otco-cli";
+
+// First pause before retrying a transient failure; doubles per attempt.
+const RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+mod cache;
+pub use cache::ResponseCache;
+#[cfg(feature = "otel")]
+mod metrics;
+#[cfg(feature = "otel")]
+pub use metrics::METER_NAME;
+/// The underlying HTTP client; build one with [`HttpClientBuilder`] and share it via [`GitHubClient::with_client`].
+pub use reqwest::Client as HttpClient;
 
 #[derive(Debug, Error)]
 pub enum ApiError {
@@ -10,6 +34,249 @@ pub enum ApiError {
     Http(#[from] reqwest::Error),
     #[error("url parse error: {0}")]
     Url(#[from] url::ParseError),
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("graphql error: {0}")]
+    Graphql(String),
+    /// The API answered 401: the token is missing, expired, or revoked for `host`.
+    #[error("unauthorized (401) for {host}: token missing or expired")]
+    Unauthorized { host: String },
+    /// The API answered 429, or 403 with `x-ratelimit-remaining: 0`; `reset` is the Unix time
+    /// from `x-ratelimit-reset`, when sent.
+    #[error("rate limited ({status}){}", .reset.map(|r| format!("; the limit resets at {r} (unix time)")).unwrap_or_default())]
+    RateLimited { status: u16, reset: Option<u64> },
+    /// The branch exists but has no protection rules (the API's 404 "Branch not protected").
+    #[error("branch '{branch}' is not protected")]
+    BranchNotProtected { branch: String },
+    #[error("request not sent (explain mode):\n{0}")]
+    Explain(RequestPlan),
+}
+
+/// A described (unsent) request: method, full URL, headers with the token redacted, and body.
+#[derive(Debug, Clone)]
+pub struct RequestPlan {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl RequestPlan {
+    pub fn from_request(req: &reqwest::Request) -> Self {
+        let headers = req
+            .headers()
+            .iter()
+            .map(|(k, v)| {
+                let value = if k == AUTHORIZATION {
+                    "<redacted>".to_string()
+                } else {
+                    v.to_str().unwrap_or_default().to_string()
+                };
+                (k.to_string(), value)
+            })
+            .collect();
+        let body = req
+            .body()
+            .and_then(|b| b.as_bytes())
+            .map(|b| String::from_utf8_lossy(b).to_string());
+        Self { method: req.method().to_string(), url: req.url().to_string(), headers, body }
+    }
+}
+
+impl std::fmt::Display for RequestPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} {}", self.method, self.url)?;
+        for (k, v) in &self.headers {
+            writeln!(f, "{k}: {v}")?;
+        }
+        if let Some(b) = &self.body {
+            writeln!(f)?;
+            writeln!(f, "{b}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ApiError {
+    /// HTTP status code of a failed response, if the error came from one.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ApiError::Http(e) => e.status().map(|s| s.as_u16()),
+            ApiError::Unauthorized { .. } => Some(401),
+            ApiError::RateLimited { status, .. } => Some(*status),
+            ApiError::BranchNotProtected { .. } => Some(404),
+            _ => None,
+        }
+    }
+}
+
+/// Builds the `reqwest::Client` (and its connection pool) used by `GitHubClient`.
+#[derive(Debug, Clone)]
+pub struct HttpClientBuilder {
+    timeout: Duration,
+    max_redirects: usize,
+    compression: bool,
+    proxy: Option<String>,
+}
+
+impl Default for HttpClientBuilder {
+    fn default() -> Self {
+        Self { timeout: Duration::from_secs(30), max_redirects: 5, compression: true, proxy: None }
+    }
+}
+
+impl HttpClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overall per-request timeout (default 30s).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Maximum redirects followed per request (default 5); 0 disables following.
+    pub fn max_redirects(mut self, max: usize) -> Self {
+        self.max_redirects = max;
+        self
+    }
+
+    /// Advertise gzip/deflate/brotli via `Accept-Encoding` and decode compressed bodies
+    /// transparently (default on); off sends no `Accept-Encoding`.
+    pub fn compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Send every request through this proxy URL (e.g. `http://proxy.internal:3128`).
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    pub fn build(self) -> Result<HttpClient, ApiError> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .redirect(redirect_policy(self.max_redirects))
+            .gzip(self.compression)
+            .deflate(self.compression)
+            .brotli(self.compression);
+        if let Some(url) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(url.as_str())?);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// Configures a [`GitHubClient`] for library use; [`GitHubClient::new`] is the defaults-only shortcut.
+#[derive(Debug, Clone, Default)]
+pub struct GitHubClientBuilder {
+    base_url: Option<String>,
+    token: Option<String>,
+    http: HttpClientBuilder,
+    client: Option<HttpClient>,
+    retries: u32,
+    accept: Option<String>,
+    api_version: Option<String>,
+    user_agent: Option<String>,
+}
+
+impl GitHubClientBuilder {
+    /// API root (default `https://api.github.com`; GHES uses `https://<host>/api/v3`).
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// Token sent as `Authorization: Bearer <token>`.
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Overall per-request timeout (default 30s).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http = self.http.timeout(timeout);
+        self
+    }
+
+    /// Retry GET requests up to `retries` more times after a connection error, timeout, or
+    /// 502/503/504, backing off 100ms, 200ms, 400ms, ... (default 0).
+    pub fn retry(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Send every request through this proxy URL.
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.http = self.http.proxy(url);
+        self
+    }
+
+    /// `Accept` header (default `application/vnd.github+json`), e.g. a preview media type.
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// `X-GitHub-Api-Version` date (default [`DEFAULT_API_VERSION`]).
+    pub fn api_version(mut self, version: impl Into<String>) -> Self {
+        self.api_version = Some(version.into());
+        self
+    }
+
+    /// `User-Agent` header (default [`DEFAULT_USER_AGENT`]); GitHub asks for the app or user name.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Use an existing HTTP client (sharing its connection pool); `timeout` and `proxy` are then ignored.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn build(self) -> Result<GitHubClient, ApiError> {
+        let http = match self.client {
+            Some(c) => c,
+            None => self.http.build()?,
+        };
+        let mut client = GitHubClient::with_client(http, self.base_url, self.token)?;
+        client.retries = self.retries;
+        if let Some(ua) = self.user_agent {
+            client.user_agent = HeaderValue::from_str(&ua).map_err(|_| ApiError::InvalidArgument(format!("invalid user agent '{ua}'")))?;
+        }
+        if let Some(version) = self.api_version {
+            client = client.with_api_version(version)?;
+        }
+        if let Some(accept) = self.accept {
+            client = client.with_extra_headers(&[("Accept".to_string(), accept)])?;
+        }
+        Ok(client)
+    }
+}
+
+// Follow a bounded number of redirects and never downgrade https to http.
+// When a hop changes host or port, reqwest drops `Authorization` (and cookies)
+// before the next request, so download redirects to blob storage never see
+// the token; `redirect_to_other_host_drops_authorization` pins that behavior.
+fn redirect_policy(max: usize) -> reqwest::redirect::Policy {
+    if max == 0 {
+        return reqwest::redirect::Policy::none();
+    }
+    reqwest::redirect::Policy::custom(move |attempt| {
+        let downgrade = attempt.url().scheme() == "http"
+            && attempt.previous().iter().any(|u| u.scheme() == "https");
+        if attempt.previous().len() > max {
+            attempt.error(format!("too many redirects (max {max})"))
+        } else if downgrade {
+            attempt.error("refusing to follow an https -> http redirect")
+        } else {
+            attempt.follow()
+        }
+    })
 }
 
 #[derive(Clone)]
@@ -17,27 +284,206 @@ pub struct GitHubClient {
     base_url: Url,
     client: reqwest::Client,
     token: Option<String>,
+    explain: bool,
+    // Requests described under `explain`, shared by clones, until `take_plans`
+    plans: Arc<Mutex<Vec<RequestPlan>>>,
+    cache: Option<Arc<ResponseCache>>,
+    page_info: Arc<Mutex<Option<PageInfo>>>,
+    extra_headers: HeaderMap,
+    max_items: Option<usize>,
+    api_version: HeaderValue,
+    wait_on_rate_limit: bool,
+    retries: u32,
+    user_agent: HeaderValue,
+    // Unix time the exhausted primary rate limit resets; the next request waits for it
+    rate_limit_reset: Arc<Mutex<Option<u64>>>,
+    stats: Arc<Mutex<RequestStats>>,
+    // Receives each page of list items as it arrives; `keep_pages: false` stops collecting them
+    page_sink: Option<PageSink>,
+    keep_pages: bool,
+}
+
+/// Callback handed each page of list items (after any `max_items` cut) as soon as it arrives.
+pub type PageSink = Arc<dyn Fn(&[serde_json::Value]) + Send + Sync>;
+
+/// Pagination summary of the most recent list call, from the first response's `Link` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageInfo {
+    /// Page number of the `rel="last"` link; `None` when the first page was also the last.
+    pub last_page: Option<u32>,
+    pub pages_fetched: u32,
+    pub per_page: u32,
+    /// Item count reported by `{ total_count, ... }` envelope responses on their first page.
+    pub total_count: Option<u64>,
+}
+
+/// Running totals for a client and its clones, read via `GitHubClient::stats()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestStats {
+    /// HTTP requests sent, one per page and per retry; cache hits send none.
+    pub requests: u32,
+    /// Items collected by list calls (after any `max_items` cap).
+    pub items: u64,
+    /// Time spent waiting for responses.
+    pub elapsed: Duration,
+    /// `x-ratelimit-remaining` of the latest response that carried it.
+    pub rate_limit_remaining: Option<u64>,
+}
+
+impl RequestStats {
+    /// Totals of two clients; the remaining rate limit is the lower of the two (the same token
+    /// drains both).
+    pub fn merge(self, other: RequestStats) -> RequestStats {
+        RequestStats {
+            requests: self.requests + other.requests,
+            items: self.items + other.items,
+            elapsed: self.elapsed + other.elapsed,
+            rate_limit_remaining: match (self.rate_limit_remaining, other.rate_limit_remaining) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+        }
+    }
+}
+
+impl PageInfo {
+    /// Upper bound on the number of available items (`last_page * per_page`).
+    pub fn approx_total(&self) -> Option<u64> {
+        self.last_page.map(|p| u64::from(p) * u64::from(self.per_page))
+    }
+}
+
+/// Extract the `page` query parameter of the `rel="last"` entry in a `Link` header.
+pub fn parse_last_page(link: &str) -> Option<u32> {
+    link.split(',')
+        .find(|part| part.contains("rel=\"last\""))
+        .and_then(|part| part.split(';').next())
+        .map(|url| url.trim().trim_start_matches('<').trim_end_matches('>'))
+        .and_then(|url| Url::parse(url).ok())
+        .and_then(|url| url.query_pairs().find(|(k, _)| k == "page").and_then(|(_, v)| v.parse().ok()))
 }
 
 impl GitHubClient {
     pub fn new(base_url: Option<String>, token: Option<String>) -> Result<Self, ApiError> {
+        GitHubClientBuilder { base_url, token, ..Default::default() }.build()
+    }
+
+    /// Start configuring a client (base URL, token, timeout, retries, proxy, headers).
+    pub fn builder() -> GitHubClientBuilder {
+        GitHubClientBuilder::default()
+    }
+
+    /// Use an existing HTTP client so several `GitHubClient`s share one connection pool. This is
+    /// the one place defaults are set; `GitHubClientBuilder::build` starts from it too.
+    pub fn with_client(client: HttpClient, base_url: Option<String>, token: Option<String>) -> Result<Self, ApiError> {
         let base = base_url
             .unwrap_or_else(|| "https://api.github.com".to_string());
         let base_url = Url::parse(&base)?;
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()?;
-        Ok(Self { base_url, client, token })
+        Ok(Self {
+            base_url,
+            client,
+            token,
+            explain: false,
+            plans: Arc::default(),
+            cache: None,
+            page_info: Arc::default(),
+            extra_headers: HeaderMap::new(),
+            max_items: None,
+            api_version: HeaderValue::from_static(DEFAULT_API_VERSION),
+            wait_on_rate_limit: false,
+            retries: 0,
+            user_agent: HeaderValue::from_static(DEFAULT_USER_AGENT),
+            rate_limit_reset: Arc::default(),
+            stats: Arc::default(),
+            page_sink: None,
+            keep_pages: true,
+        })
+    }
+
+    /// Describe requests instead of sending them; each call fails with `ApiError::Explain` and
+    /// records its plan, so callers that keep going after a failed call can list every request
+    /// with `take_plans`.
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    /// Drain the plans recorded by explain mode (across clones), in the order they were built.
+    pub fn take_plans(&self) -> Vec<RequestPlan> {
+        std::mem::take(&mut *self.plans.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Serve GET responses from an on-disk cache while they are younger than its TTL.
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Stop paginating list calls once this many items are collected (extra items are dropped).
+    pub fn with_max_items(mut self, max_items: Option<usize>) -> Self {
+        self.max_items = max_items;
+        self
+    }
+
+    /// Pass every page of REST list items to `sink` as it arrives. With `keep: false` the items
+    /// are not also collected, so list calls return an empty `Vec` and memory stays flat however
+    /// many pages arrive; `max_items` and `total_count` still end paging as usual.
+    pub fn with_page_sink(mut self, sink: PageSink, keep: bool) -> Self {
+        self.page_sink = Some(sink);
+        self.keep_pages = keep;
+        self
+    }
+
+    /// When a response reports `x-ratelimit-remaining: 0`, sleep until `x-ratelimit-reset` before
+    /// the next request (and retry a request rejected for the exhausted limit) instead of failing.
+    pub fn with_wait_on_rate_limit(mut self, wait: bool) -> Self {
+        self.wait_on_rate_limit = wait;
+        self
+    }
+
+    /// Send `X-GitHub-Api-Version: <version>` (a `YYYY-MM-DD` date) instead of `DEFAULT_API_VERSION`.
+    pub fn with_api_version(mut self, version: String) -> Result<Self, ApiError> {
+        let b = version.as_bytes();
+        let shaped = b.len() == 10 && b.iter().enumerate().all(|(i, c)| if i == 4 || i == 7 { *c == b'-' } else { c.is_ascii_digit() });
+        if !shaped {
+            return Err(ApiError::InvalidArgument(format!("invalid API version '{version}' (expected YYYY-MM-DD, e.g. {DEFAULT_API_VERSION})")));
+        }
+        self.api_version = HeaderValue::from_str(&version).map_err(|_| ApiError::InvalidArgument(format!("invalid API version '{version}'")))?;
+        Ok(self)
+    }
+
+    /// Send these headers on every request, replacing defaults of the same name (e.g. a preview
+    /// `Accept`). `Authorization` is refused so the token can only come from the client itself.
+    pub fn with_extra_headers(mut self, headers: &[(String, String)]) -> Result<Self, ApiError> {
+        for (name, value) in headers {
+            let name = HeaderName::from_bytes(name.trim().as_bytes())
+                .map_err(|_| ApiError::InvalidArgument(format!("invalid header name '{name}'")))?;
+            if name == AUTHORIZATION {
+                return Err(ApiError::InvalidArgument("the Authorization header cannot be overridden".into()));
+            }
+            let value = HeaderValue::from_str(value.trim())
+                .map_err(|_| ApiError::InvalidArgument(format!("invalid value for header '{name}'")))?;
+            self.extra_headers.insert(name, value);
+        }
+        Ok(self)
+    }
+
+    /// Pagination info recorded by the latest list call on this client (or a clone of it).
+    pub fn page_info(&self) -> Option<PageInfo> {
+        *self.page_info.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Requests, items, and response time so far, shared with clones of this client.
+    pub fn stats(&self) -> RequestStats {
+        *self.stats.lock().unwrap_or_else(|e| e.into_inner())
     }
 
     fn headers(&self) -> HeaderMap {
         let mut headers = HeaderMap::new();
-        headers.insert(USER_AGENT, HeaderValue::from_static("gh-otco-cli"));
+        headers.insert(USER_AGENT, self.user_agent.clone());
         headers.insert(ACCEPT, HeaderValue::from_static("application/vnd.github+json"));
-        headers.insert(
-            HeaderName::from_static("x-github-api-version"),
-            HeaderValue::from_static("2022-11-28"),
-        );
+        headers.insert(HeaderName::from_static("x-github-api-version"), self.api_version.clone());
+        headers.extend(self.extra_headers.clone());
         if let Some(t) = &self.token {
             let value = format!("Bearer {}", t);
             if let Ok(val) = HeaderValue::from_str(&value) {
@@ -51,43 +497,295 @@ impl GitHubClient {
         Ok(self.base_url.join(path)?)
     }
 
+    /// Build a request without sending it. `execute` sends it; `--explain` only describes it.
+    pub fn build_request(
+        &self,
+        method: Method,
+        path: &str,
+        params: &[(&str, String)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<reqwest::Request, ApiError> {
+        let url = self.url(path)?;
+        let mut rb = self.client.request(method, url).headers(self.headers());
+        if !params.is_empty() {
+            rb = rb.query(params);
+        }
+        if let Some(b) = body {
+            rb = rb.json(b);
+        }
+        Ok(rb.build()?)
+    }
+
+    async fn execute(&self, req: reqwest::Request) -> Result<reqwest::Response, ApiError> {
+        Ok(self.execute_unchecked(req).await?.error_for_status()?)
+    }
+
+    // `execute` without turning 4xx/5xx into errors (401 and rate limits still are), for callers
+    // that read the error body.
+    async fn execute_unchecked(&self, req: reqwest::Request) -> Result<reqwest::Response, ApiError> {
+        if self.explain {
+            let plan = RequestPlan::from_request(&req);
+            self.plans.lock().unwrap_or_else(|e| e.into_inner()).push(plan.clone());
+            return Err(ApiError::Explain(plan));
+        }
+        let traced = tracing::enabled!(target: HTTP_LOG_TARGET, tracing::Level::DEBUG);
+        if traced {
+            let plan = RequestPlan::from_request(&req);
+            let headers = plan.headers.iter().map(|(k, v)| format!("{k}: {v}")).collect::<Vec<_>>().join(", ");
+            tracing::debug!(target: HTTP_LOG_TARGET, method = %plan.method, url = %plan.url, headers = %headers, "http request");
+        }
+        #[cfg(feature = "otel")]
+        let method = req.method().to_string();
+        self.wait_for_rate_limit_reset().await;
+        let retry = req.try_clone().filter(|_| self.wait_on_rate_limit);
+        let started = Instant::now();
+        let mut res = self.send(req).await?;
+        if self.wait_on_rate_limit {
+            self.note_rate_limit(&res);
+            // Rejected because the primary limit is spent: wait for the reset and send it again
+            let limited = matches!(res.status().as_u16(), 403 | 429);
+            if let Some(req) = retry.filter(|_| limited && self.rate_limit_reset.lock().unwrap_or_else(|e| e.into_inner()).is_some()) {
+                self.wait_for_rate_limit_reset().await;
+                res = self.send(req).await?;
+                self.note_rate_limit(&res);
+            }
+        }
+        {
+            let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+            stats.elapsed += started.elapsed();
+            if let Some(remaining) = res.headers().get("x-ratelimit-remaining").and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok()) {
+                stats.rate_limit_remaining = Some(remaining);
+            }
+        }
+        #[cfg(feature = "otel")]
+        {
+            let remaining = res.headers().get("x-ratelimit-remaining").and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok());
+            metrics::record_response(&method, res.status().as_u16(), started.elapsed(), remaining);
+        }
+        if traced {
+            let remaining = res.headers().get("x-ratelimit-remaining").and_then(|v| v.to_str().ok()).unwrap_or("-");
+            tracing::debug!(
+                target: HTTP_LOG_TARGET,
+                status = res.status().as_u16(),
+                url = %res.url(),
+                elapsed_ms = started.elapsed().as_millis() as u64,
+                ratelimit_remaining = remaining,
+                "http response"
+            );
+        }
+        if res.status() == reqwest::StatusCode::UNAUTHORIZED {
+            // Name the configured host (the keyring key), not a redirect target
+            let host = self.base_url.host_str().unwrap_or_default().to_string();
+            return Err(ApiError::Unauthorized { host });
+        }
+        let header = |name: &str| res.headers().get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse::<u64>().ok());
+        let status = res.status().as_u16();
+        if status == 429 || (status == 403 && header("x-ratelimit-remaining") == Some(0)) {
+            return Err(ApiError::RateLimited { status, reset: header("x-ratelimit-reset") });
+        }
+        Ok(res)
+    }
+
+    // Send `req`, retrying GETs that hit a transient failure while `retries` allows.
+    async fn send(&self, mut req: reqwest::Request) -> Result<reqwest::Response, ApiError> {
+        let mut attempt = 0u32;
+        loop {
+            let spare = req.try_clone().filter(|r| attempt < self.retries && r.method() == Method::GET);
+            self.stats.lock().unwrap_or_else(|e| e.into_inner()).requests += 1;
+            let outcome = self.client.execute(req).await;
+            let transient = match &outcome {
+                Ok(res) => matches!(res.status().as_u16(), 502..=504),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            };
+            match spare {
+                Some(next) if transient => {
+                    tokio::time::sleep(RETRY_BACKOFF * 2u32.pow(attempt)).await;
+                    attempt += 1;
+                    req = next;
+                }
+                _ => return Ok(outcome?),
+            }
+        }
+    }
+
+    // Remember the reset time when this response spent the last request of the primary limit.
+    fn note_rate_limit(&self, res: &reqwest::Response) {
+        let header = |name: &str| res.headers().get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse::<u64>().ok());
+        if header("x-ratelimit-remaining") == Some(0) {
+            *self.rate_limit_reset.lock().unwrap_or_else(|e| e.into_inner()) = header("x-ratelimit-reset");
+        }
+    }
+
+    // Sleep until a noted rate-limit reset has passed, logging when work resumes.
+    async fn wait_for_rate_limit_reset(&self) {
+        let Some(reset) = self.rate_limit_reset.lock().unwrap_or_else(|e| e.into_inner()).take() else { return };
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_secs();
+        let wait = reset.saturating_sub(now);
+        if wait > 0 {
+            tracing::warn!(reset, wait_secs = wait, "rate limit exhausted; waiting {wait}s for it to reset");
+            tokio::time::sleep(std::time::Duration::from_secs(wait)).await;
+        }
+    }
+
     pub async fn rate_limit(&self) -> Result<RateLimit, ApiError> {
-        let url = self.url("/rate_limit")?;
-        let res = self
-            .client
-            .get(url)
-            .headers(self.headers())
-            .send()
-            .await?
-            .error_for_status()?;
+        let req = self.build_request(Method::GET, "/rate_limit", &[], None)?;
+        let res = self.execute(req).await?;
         Ok(res.json::<RateLimit>().await?)
     }
 
+    // Meta: `/meta` (IP ranges, SSH key fingerprints, `installed_version` on GHES)
+    pub async fn meta(&self) -> Result<serde_json::Value, ApiError> {
+        self.get_json("/meta", &[]).await
+    }
+
+    // Meta: `/zen`, a random design aphorism as plain text (a cheap reachability and auth check)
+    pub async fn zen(&self) -> Result<String, ApiError> {
+        self.get_text("/zen", &[]).await
+    }
+
+    // Meta: `/octocat` ASCII art, optionally saying `say`
+    pub async fn octocat(&self, say: Option<&str>) -> Result<String, ApiError> {
+        let mut params = Vec::new();
+        if let Some(s) = say { params.push(("s", s.to_string())); }
+        self.get_text("/octocat", &params).await
+    }
+
     pub async fn current_user(&self) -> Result<User, ApiError> {
-        let url = self.url("/user")?;
-        let res = self
-            .client
-            .get(url)
-            .headers(self.headers())
-            .send()
-            .await?
-            .error_for_status()?;
+        let req = self.build_request(Method::GET, "/user", &[], None)?;
+        let res = self.execute(req).await?;
         Ok(res.json::<User>().await?)
     }
 
+    // The authenticated user, plus `/user/emails` (needs `user:email`) and `/user/orgs` (private
+    // memberships need `read:org`) only when asked, since each costs requests.
+    pub async fn current_user_extended(&self, emails: bool, orgs: bool) -> Result<UserProfile, ApiError> {
+        // Independent requests, so they run together (and --explain describes all of them);
+        // errors are reported in request order
+        let (user, emails, orgs) = tokio::join!(
+            self.current_user(),
+            async {
+                match emails {
+                    true => self.get_all_pages_array("/user/emails", Vec::new(), 100, None).await.map(Some),
+                    false => Ok(None),
+                }
+            },
+            async {
+                match orgs {
+                    true => self.get_all_pages_array("/user/orgs", Vec::new(), 100, None).await.map(Some),
+                    false => Ok(None),
+                }
+            },
+        );
+        let (user, emails) = (user?, emails?);
+        let orgs = orgs?.map(|list| list.iter().filter_map(|o| o["login"].as_str().map(str::to_string)).collect());
+        Ok(UserProfile { user, emails, orgs })
+    }
+
+    // Scopes of a classic token from `X-OAuth-Scopes` on `/user`; `None` when the header is
+    // absent (fine-grained and app tokens don't report scopes).
+    pub async fn token_scopes(&self) -> Result<Option<Vec<String>>, ApiError> {
+        let req = self.build_request(Method::GET, "/user", &[], None)?;
+        let res = self.execute(req).await?;
+        let header = res.headers().get("x-oauth-scopes").and_then(|v| v.to_str().ok());
+        Ok(header.map(|h| h.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect()))
+    }
+
+    // GET for endpoints that answer with plain text rather than JSON.
+    async fn get_text(&self, path: &str, params: &[(&str, String)]) -> Result<String, ApiError> {
+        let req = self.build_request(Method::GET, path, params, None)?;
+        let res = self.execute(req).await?;
+        Ok(res.text().await?)
+    }
+
     async fn get_json(&self, path: &str, params: &[(&str, String)]) -> Result<serde_json::Value, ApiError> {
-        let url = self.url(path)?;
-        let res = self
-            .client
-            .get(url)
-            .headers(self.headers())
-            .query(&params)
-            .send()
-            .await?
-            .error_for_status()?;
+        Ok(self.get_json_with_link(path, params).await?.0)
+    }
+
+    // GET returning the body and the `Link` header (both cached together).
+    async fn get_json_with_link(&self, path: &str, params: &[(&str, String)]) -> Result<(serde_json::Value, Option<String>), ApiError> {
+        let req = self.build_request(Method::GET, path, params, None)?;
+        let cache = self.cache.as_ref().filter(|_| !self.explain);
+        let key = ResponseCache::key_for(&format!("{} {}", req.method(), req.url()), self.token.as_deref());
+        if let Some(hit) = cache.and_then(|c| c.get(&key)) {
+            return Ok(hit);
+        }
+        let res = self.execute(req).await?;
+        let link = res.headers().get(reqwest::header::LINK).and_then(|v| v.to_str().ok()).map(str::to_string);
+        let v = res.json::<serde_json::Value>().await?;
+        if let Some(c) = cache {
+            c.put(&key, &v, link.as_deref());
+        }
+        Ok((v, link))
+    }
+
+    async fn send_json(&self, method: Method, path: &str, body: &serde_json::Value) -> Result<serde_json::Value, ApiError> {
+        let req = self.build_request(method, path, &[], Some(body))?;
+        let res = self.execute(req).await?;
         Ok(res.json::<serde_json::Value>().await?)
     }
 
+    // Request with no body whose success response (e.g. 204 No Content) carries nothing to return.
+    async fn send_empty(&self, method: Method, path: &str) -> Result<(), ApiError> {
+        let req = self.build_request(method, path, &[], None)?;
+        self.execute(req).await?;
+        Ok(())
+    }
+
+    // GHES serves GraphQL at /api/graphql next to the REST root /api/v3.
+    fn graphql_path(&self) -> &'static str {
+        if self.base_url.path().trim_end_matches('/').ends_with("/api/v3") { "/api/graphql" } else { "/graphql" }
+    }
+
+    /// Run a GraphQL query and return its `data`; a response listing `errors` fails with
+    /// `ApiError::Graphql` even though the HTTP status is 200.
+    pub async fn graphql(&self, query: &str, variables: serde_json::Value) -> Result<serde_json::Value, ApiError> {
+        let body = serde_json::json!({ "query": query, "variables": variables });
+        let mut res = self.send_json(Method::POST, self.graphql_path(), &body).await?;
+        if let Some(errors) = res.get("errors").and_then(|e| e.as_array()).filter(|e| !e.is_empty()) {
+            let messages: Vec<&str> = errors.iter().filter_map(|e| e["message"].as_str()).collect();
+            return Err(ApiError::Graphql(messages.join("; ")));
+        }
+        Ok(res["data"].take())
+    }
+
+    /// Collect `nodes` from the connection at `page_path` (dotted, relative to `data`, e.g.
+    /// `repository.issues`), following `pageInfo { hasNextPage endCursor }` for up to
+    /// `max_pages` requests (`None` = until the last page). The query must declare
+    /// `$cursor: String` and pass it as `after:` on that connection.
+    pub async fn graphql_paginate(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        page_path: &str,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let mut vars = match variables {
+            serde_json::Value::Object(map) => map,
+            serde_json::Value::Null => serde_json::Map::new(),
+            _ => return Err(ApiError::InvalidArgument("graphql variables must be a JSON object".into())),
+        };
+        let mut out = Vec::new();
+        let mut page = 1u32;
+        loop {
+            let data = self.graphql(query, serde_json::Value::Object(vars.clone())).await?;
+            let conn = page_path
+                .split('.')
+                .try_fold(&data, |cur, seg| cur.get(seg))
+                .ok_or_else(|| ApiError::InvalidArgument(format!("no connection at '{page_path}' in the GraphQL response")))?;
+            if let Some(nodes) = conn["nodes"].as_array() {
+                out.extend(nodes.iter().cloned());
+            }
+            let info = &conn["pageInfo"];
+            match info["endCursor"].as_str().filter(|_| info["hasNextPage"] == true) {
+                Some(cursor) if max_pages.is_none_or(|m| page < m) => { vars.insert("cursor".into(), cursor.into()); }
+                _ => break,
+            }
+            page += 1;
+        }
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).items += out.len() as u64;
+        Ok(out)
+    }
+
     async fn get_all_pages_array(
         &self,
         path: &str,
@@ -95,36 +793,109 @@ impl GitHubClient {
         per_page: u32,
         max_pages: Option<u32>,
     ) -> Result<Vec<serde_json::Value>, ApiError> {
+        self.get_all_pages(path, None, params, per_page, max_pages, None).await.map(|(items, _)| items)
+    }
+
+    // Like `get_all_pages_array` for endpoints that wrap each page as `{ total_count, "<key>": [...] }`.
+    // Returns the items of every page plus the first page's `total_count` (also kept in `page_info`).
+    async fn get_all_pages_envelope(
+        &self,
+        path: &str,
+        key: &str,
+        params: Vec<(&str, String)>,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<(Vec<serde_json::Value>, Option<u64>), ApiError> {
+        self.get_all_pages(path, Some(key), params, per_page, max_pages, None).await
+    }
+
+    async fn get_all_pages(
+        &self,
+        path: &str,
+        envelope: Option<&str>,
+        params: Vec<(&str, String)>,
+        per_page: u32,
+        max_pages: Option<u32>,
+        keep: Option<fn(&serde_json::Value) -> bool>,
+    ) -> Result<(Vec<serde_json::Value>, Option<u64>), ApiError> {
+        // `max_pages: None` pages until an empty page; `max_items` can end it sooner.
+        // A cap below one page only needs a page of that size, unless `keep` drops items.
+        let per_page = match keep {
+            None => self.max_items.map_or(per_page, |m| per_page.min(u32::try_from(m.max(1)).unwrap_or(u32::MAX))),
+            Some(_) => per_page,
+        };
         let mut page = 1u32;
         let mut out = Vec::new();
-        let max_pages = max_pages.unwrap_or(10); // guard to avoid accidental huge fetches
+        let mut seen = 0usize;
+        let mut total_count = None;
         loop {
             let mut q = params.clone();
             q.push(("per_page", per_page.to_string()));
             q.push(("page", page.to_string()));
-            let v = self.get_json(path, &q).await?;
+            let (v, link) = self.get_json_with_link(path, &q).await?;
+            if page == 1 {
+                total_count = envelope.and_then(|_| v.get("total_count")).and_then(|t| t.as_u64());
+                *self.page_info.lock().unwrap_or_else(|e| e.into_inner()) =
+                    Some(PageInfo { last_page: link.as_deref().and_then(parse_last_page), pages_fetched: 0, per_page, total_count });
+            }
+            if let Some(info) = self.page_info.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+                info.pages_fetched = page;
+            }
+            let v = match envelope {
+                Some(key) => v.get(key).cloned().unwrap_or_default(),
+                None => v,
+            };
             match v {
                 serde_json::Value::Array(mut arr) => {
                     let len = arr.len();
-                    out.append(&mut arr);
-                    if len == 0 || page >= max_pages { break; }
+                    // Filtered-out items don't count against `max_items`
+                    if let Some(keep) = keep {
+                        arr.retain(keep);
+                    }
+                    let full = self.max_items.is_some_and(|m| seen + arr.len() >= m);
+                    if let Some(max) = self.max_items {
+                        arr.truncate(max.saturating_sub(seen));
+                    }
+                    seen += arr.len();
+                    if let Some(sink) = self.page_sink.as_ref().filter(|_| !arr.is_empty()) {
+                        sink(&arr);
+                    }
+                    if self.keep_pages {
+                        out.append(&mut arr);
+                    }
+                    if full || len == 0 || max_pages.is_some_and(|m| page >= m) { break; }
+                    // Envelopes say how many items exist; stop once they are all here
+                    if total_count.is_some_and(|t| seen as u64 >= t) { break; }
                 }
                 _ => break,
             }
             page += 1;
         }
-        Ok(out)
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).items += seen as u64;
+        Ok((out, total_count))
+    }
+
+    // Org: profile (plan, repo counts, security defaults for new repos)
+    pub async fn get_org(&self, org: &str) -> Result<serde_json::Value, ApiError> {
+        let path = format!("/orgs/{org}");
+        self.get_json(&path, &[]).await
     }
 
     pub async fn list_org_repos(
         &self,
         org: &str,
         kind: Option<&str>, // all, public, private, forks, sources, member
+        sort: Option<&str>, // created, updated, pushed, full_name
+        direction: Option<&str>, // asc, desc
         per_page: u32,
         max_pages: Option<u32>,
     ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(s) = sort { check_enum("sort", s, ORG_REPO_SORTS)?; }
+        if let Some(d) = direction { check_enum("direction", d, SORT_DIRECTIONS)?; }
         let mut params = Vec::new();
         if let Some(k) = kind { params.push(("type", k.to_string())); }
+        if let Some(s) = sort { params.push(("sort", s.to_string())); }
+        if let Some(d) = direction { params.push(("direction", d.to_string())); }
         let path = format!("/orgs/{org}/repos");
         self.get_all_pages_array(&path, params, per_page, max_pages).await
     }
@@ -136,65 +907,396 @@ impl GitHubClient {
         state: Option<&str>, // open, closed, all
         labels: Option<&str>,
         assignee: Option<&str>,
+        creator: Option<&str>,
+        mentioned: Option<&str>,
         milestone: Option<&str>,
         since: Option<&str>, // ISO 8601
+        sort: Option<&str>, // created, updated, comments
+        direction: Option<&str>, // asc, desc
         per_page: u32,
         max_pages: Option<u32>,
     ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(s) = state { check_enum("state", s, ISSUE_STATES)?; }
+        if let Some(s) = sort { check_enum("sort", s, ISSUE_SORTS)?; }
+        if let Some(d) = direction { check_enum("direction", d, SORT_DIRECTIONS)?; }
         let mut params = Vec::new();
         if let Some(s) = state { params.push(("state", s.to_string())); }
         if let Some(l) = labels { params.push(("labels", l.to_string())); }
         if let Some(a) = assignee { params.push(("assignee", a.to_string())); }
+        if let Some(c) = creator { params.push(("creator", c.to_string())); }
+        if let Some(m) = mentioned { params.push(("mentioned", m.to_string())); }
         if let Some(m) = milestone { params.push(("milestone", m.to_string())); }
         if let Some(since) = since { params.push(("since", since.to_string())); }
+        if let Some(s) = sort { params.push(("sort", s.to_string())); }
+        if let Some(d) = direction { params.push(("direction", d.to_string())); }
         let path = format!("/repos/{owner}/{repo}/issues");
         self.get_all_pages_array(&path, params, per_page, max_pages).await
     }
 
+    // Repo: list pull requests. `merged` is not an API state: it asks for `closed` and keeps the
+    // pulls with a `merged_at`, so a page can come back with fewer items than `per_page`.
+    // A `head` without `owner:` is taken as a branch of `owner`.
     pub async fn list_repo_pulls(
         &self,
         owner: &str,
         repo: &str,
-        state: Option<&str>, // open, closed, all
+        state: Option<&str>, // open, closed, all, merged
         draft: Option<bool>,
         base: Option<&str>,
+        head: Option<&str>, // user:branch
+        sort: Option<&str>, // created, updated, popularity, long-running
+        direction: Option<&str>, // asc, desc
         per_page: u32,
         max_pages: Option<u32>,
     ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(s) = state { check_enum("state", s, PULL_STATES)?; }
+        if let Some(s) = sort { check_enum("sort", s, PULL_SORTS)?; }
+        if let Some(d) = direction { check_enum("direction", d, SORT_DIRECTIONS)?; }
+        let merged = state == Some("merged");
         let mut params = Vec::new();
-        if let Some(s) = state { params.push(("state", s.to_string())); }
+        if let Some(s) = state { params.push(("state", if merged { "closed".to_string() } else { s.to_string() })); }
         if let Some(d) = draft { params.push(("draft", d.to_string())); }
         if let Some(b) = base { params.push(("base", b.to_string())); }
+        if let Some(h) = head { params.push(("head", if h.contains(':') { h.to_string() } else { format!("{owner}:{h}") })); }
+        if let Some(s) = sort { params.push(("sort", s.to_string())); }
+        if let Some(d) = direction { params.push(("direction", d.to_string())); }
         let path = format!("/repos/{owner}/{repo}/pulls");
+        // Each page is filtered before it counts against `max_items`, so --limit means merged PRs
+        let keep: Option<fn(&serde_json::Value) -> bool> = merged.then_some(|p| !p["merged_at"].is_null());
+        self.get_all_pages(&path, None, params, per_page, max_pages, keep).await.map(|(items, _)| items)
+    }
+
+    // Repo: list milestones with filters
+    pub async fn list_repo_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>, // open, closed, all
+        sort: Option<&str>, // due_on, completeness
+        direction: Option<&str>, // asc, desc
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(s) = state { check_enum("state", s, ISSUE_STATES)?; }
+        if let Some(s) = sort { check_enum("sort", s, MILESTONE_SORTS)?; }
+        if let Some(d) = direction { check_enum("direction", d, SORT_DIRECTIONS)?; }
+        let mut params = Vec::new();
+        if let Some(s) = state { params.push(("state", s.to_string())); }
+        if let Some(s) = sort { params.push(("sort", s.to_string())); }
+        if let Some(d) = direction { params.push(("direction", d.to_string())); }
+        let path = format!("/repos/{owner}/{repo}/milestones");
         self.get_all_pages_array(&path, params, per_page, max_pages).await
     }
 
-    // Actions: list workflows in a repo
-    pub async fn list_repo_workflows(
+    // Repo: metadata (clone URLs, default branch, visibility, counts)
+    pub async fn get_repo(&self, owner: &str, repo: &str) -> Result<serde_json::Value, ApiError> {
+        let path = format!("/repos/{owner}/{repo}");
+        self.get_json(&path, &[]).await
+    }
+
+    // Repo: language breakdown (language -> bytes)
+    pub async fn get_repo_languages(
         &self,
         owner: &str,
         repo: &str,
     ) -> Result<serde_json::Value, ApiError> {
-        let path = format!("/repos/{owner}/{repo}/actions/workflows");
+        let path = format!("/repos/{owner}/{repo}/languages");
         self.get_json(&path, &[]).await
     }
 
-    // Actions: list workflow runs in a repo with filters
+    // Repo: README decoded to text (default branch unless `ref_` is given)
+    pub async fn get_repo_readme(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: Option<&str>,
+    ) -> Result<String, ApiError> {
+        let mut params = Vec::new();
+        if let Some(r) = ref_ { params.push(("ref", r.to_string())); }
+        let path = format!("/repos/{owner}/{repo}/readme");
+        let v = self.get_json(&path, &params).await?;
+        decode_content(&v)
+    }
+
+    // Repo: topics (response is wrapped as `{ names: [...] }`; the default Accept now covers it,
+    // older GHES releases may still want `--header "Accept: application/vnd.github.mercy-preview+json"`)
+    pub async fn get_repo_topics(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<String>, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/topics");
+        let v = self.get_json(&path, &[]).await?;
+        Ok(v["names"].as_array().into_iter().flatten().filter_map(|n| n.as_str().map(str::to_string)).collect())
+    }
+
+    // Repo: compare two refs (`base...head`: status, ahead_by, behind_by, total_commits, commits, files)
+    pub async fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<serde_json::Value, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/compare/{base}...{head}");
+        self.get_json(&path, &[]).await
+    }
+
+    // Repo: page views over the last 14 days, bucketed `per` day or week (`{ count, uniques, views: [...] }`;
+    // requires push access)
+    pub async fn get_repo_views(&self, owner: &str, repo: &str, per: &str) -> Result<serde_json::Value, ApiError> {
+        check_enum("per", per, TRAFFIC_PERIODS)?;
+        let path = format!("/repos/{owner}/{repo}/traffic/views");
+        self.get_json(&path, &[("per", per.to_string())]).await
+    }
+
+    // Repo: clones over the last 14 days, bucketed `per` day or week (`{ count, uniques, clones: [...] }`;
+    // requires push access)
+    pub async fn get_repo_clones(&self, owner: &str, repo: &str, per: &str) -> Result<serde_json::Value, ApiError> {
+        check_enum("per", per, TRAFFIC_PERIODS)?;
+        let path = format!("/repos/{owner}/{repo}/traffic/clones");
+        self.get_json(&path, &[("per", per.to_string())]).await
+    }
+
+    // Repo: protection rules of a branch (required status checks and reviews, admin enforcement, ...;
+    // needs admin access). The API answers 404 both for a missing branch and for one without
+    // protection; the latter becomes `ApiError::BranchNotProtected`.
+    pub async fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<serde_json::Value, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/branches/{branch}/protection");
+        let req = self.build_request(Method::GET, &path, &[], None)?;
+        let res = self.execute_unchecked(req).await?;
+        if let Err(e) = res.error_for_status_ref() {
+            if res.status() == reqwest::StatusCode::NOT_FOUND {
+                let body = res.json::<serde_json::Value>().await.unwrap_or_default();
+                if body["message"].as_str().is_some_and(|m| m.eq_ignore_ascii_case("Branch not protected")) {
+                    return Err(ApiError::BranchNotProtected { branch: branch.to_string() });
+                }
+            }
+            return Err(e.into());
+        }
+        Ok(res.json::<serde_json::Value>().await?)
+    }
+
+    // Repo: list forks
+    pub async fn list_repo_forks(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: Option<&str>, // newest, oldest, stargazers, watchers
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let mut params = Vec::new();
+        if let Some(s) = sort { params.push(("sort", s.to_string())); }
+        let path = format!("/repos/{owner}/{repo}/forks");
+        self.get_all_pages_array(&path, params, per_page, max_pages).await
+    }
+
+    // Repo: list users who starred the repo
+    pub async fn list_repo_stargazers(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/stargazers");
+        self.get_all_pages_array(&path, Vec::new(), per_page, max_pages).await
+    }
+
+    // Repo: list collaborators (needs push access to the repo)
+    pub async fn list_repo_collaborators(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: Option<&str>, // outside, direct, all
+        permission: Option<&str>, // pull, triage, push, maintain, admin
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(a) = affiliation { check_enum("affiliation", a, COLLABORATOR_AFFILIATIONS)?; }
+        if let Some(p) = permission { check_enum("permission", p, COLLABORATOR_PERMISSIONS)?; }
+        let mut params = Vec::new();
+        if let Some(a) = affiliation { params.push(("affiliation", a.to_string())); }
+        if let Some(p) = permission { params.push(("permission", p.to_string())); }
+        let path = format!("/repos/{owner}/{repo}/collaborators");
+        self.get_all_pages_array(&path, params, per_page, max_pages).await
+    }
+
+    // Repo: deployments, optionally filtered by environment name
+    pub async fn list_repo_deployments(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment: Option<&str>,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let mut params = Vec::new();
+        if let Some(e) = environment { params.push(("environment", e.to_string())); }
+        let path = format!("/repos/{owner}/{repo}/deployments");
+        self.get_all_pages_array(&path, params, per_page, max_pages).await
+    }
+
+    // Repo: deployment environments (response is wrapped as `{ total_count, environments }`)
+    pub async fn list_repo_environments(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/environments");
+        let (environments, _) = self.get_all_pages_envelope(&path, "environments", Vec::new(), per_page, max_pages).await?;
+        Ok(environments)
+    }
+
+    // Repo: recent activity events. GitHub serves at most `REPO_EVENTS_CAP` events (10 pages),
+    // and asking past that fails, so the page count is clamped whatever the caller passes.
+    pub async fn list_repo_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let per_page = per_page.clamp(1, 100);
+        let cap_pages = (REPO_EVENTS_CAP as u32).div_ceil(per_page).min(10);
+        let max_pages = max_pages.map_or(cap_pages, |p| p.min(cap_pages));
+        let path = format!("/repos/{owner}/{repo}/events");
+        let mut events = self.get_all_pages_array(&path, Vec::new(), per_page, Some(max_pages)).await?;
+        events.truncate(REPO_EVENTS_CAP);
+        Ok(events)
+    }
+
+    // Repo: an issue's event timeline (labeled, assigned, closed, referenced, ...); pull requests share the endpoint
+    pub async fn list_issue_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/issues/{number}/events");
+        self.get_all_pages_array(&path, Vec::new(), per_page, max_pages).await
+    }
+
+    // Actions: list workflows in a repo (response is wrapped as `{ total_count, workflows }`)
+    pub async fn list_repo_workflows(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/actions/workflows");
+        let (workflows, _) = self.get_all_pages_envelope(&path, "workflows", Vec::new(), per_page, max_pages).await?;
+        Ok(workflows)
+    }
+
+    // Actions: repository secrets as `{ total_count, secrets }` of names and timestamps; the API never returns values
+    pub async fn list_repo_action_secrets(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/actions/secrets");
+        let (secrets, _) = self.get_all_pages_envelope(&path, "secrets", Vec::new(), per_page, max_pages).await?;
+        Ok(secrets)
+    }
+
+    // Actions: repository variables (wrapped as `{ total_count, variables }`)
+    pub async fn list_repo_action_variables(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/actions/variables");
+        let (variables, _) = self.get_all_pages_envelope(&path, "variables", Vec::new(), per_page, max_pages).await?;
+        Ok(variables)
+    }
+
+    // Actions: enable or disable a workflow, by numeric ID or file name (e.g. `ci.yml`); 204 either way
+    pub async fn set_workflow_state(&self, owner: &str, repo: &str, workflow_id: &str, enabled: bool) -> Result<(), ApiError> {
+        let action = if enabled { "enable" } else { "disable" };
+        let path = format!("/repos/{owner}/{repo}/actions/workflows/{workflow_id}/{action}");
+        self.send_empty(Method::PUT, &path).await
+    }
+
+    // Actions: list workflow runs in a repo with filters (wrapped as `{ total_count, workflow_runs }`)
     pub async fn list_repo_workflow_runs(
         &self,
         owner: &str,
         repo: &str,
         branch: Option<&str>,
-        status: Option<&str>, // queued, in_progress, completed
+        status: Option<&str>, // queued, in_progress, completed (or a conclusion)
         conclusion: Option<&str>, // success, failure, etc.
+        event: Option<&str>, // push, pull_request, schedule, workflow_dispatch, ...
+        actor: Option<&str>, // login of the user who triggered the run
+        created: Option<&str>, // date or range in search syntax, e.g. >=2024-05-01 or 2024-05-01..2024-05-31
         per_page: u32,
         max_pages: Option<u32>,
     ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(s) = status { check_enum("status", s, WORKFLOW_RUN_STATUSES)?; }
         let mut params = Vec::new();
         if let Some(b) = branch { params.push(("branch", b.to_string())); }
         if let Some(s) = status { params.push(("status", s.to_string())); }
         if let Some(c) = conclusion { params.push(("conclusion", c.to_string())); }
+        if let Some(e) = event { params.push(("event", e.to_string())); }
+        if let Some(a) = actor { params.push(("actor", a.to_string())); }
+        if let Some(c) = created { params.push(("created", c.to_string())); }
         let path = format!("/repos/{owner}/{repo}/actions/runs");
+        let (runs, _) = self.get_all_pages_envelope(&path, "workflow_runs", params, per_page, max_pages).await?;
+        Ok(runs)
+    }
+
+    // Actions: billable time of a workflow run per runner OS (`{ billable: { UBUNTU: { total_ms, jobs, job_runs }, ... },
+    // run_duration_ms }`)
+    pub async fn get_workflow_run_usage(&self, owner: &str, repo: &str, run_id: u64) -> Result<serde_json::Value, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/timing");
+        self.get_json(&path, &[]).await
+    }
+
+    // Notifications: the authenticated user's notification threads
+    pub async fn list_notifications(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<&str>, // ISO 8601
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let mut params = Vec::new();
+        if all { params.push(("all", "true".to_string())); }
+        if participating { params.push(("participating", "true".to_string())); }
+        if let Some(s) = since { params.push(("since", s.to_string())); }
+        self.get_all_pages_array("/notifications", params, per_page, max_pages).await
+    }
+
+    // Gists: a user's public gists, or the authenticated user's gists (including secret ones)
+    pub async fn list_gists(
+        &self,
+        user: Option<&str>,
+        since: Option<&str>, // ISO 8601
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let mut params = Vec::new();
+        if let Some(s) = since { params.push(("since", s.to_string())); }
+        let path = match user {
+            Some(u) => format!("/users/{u}/gists"),
+            None => "/gists".to_string(),
+        };
         self.get_all_pages_array(&path, params, per_page, max_pages).await
     }
 
@@ -208,6 +1310,7 @@ impl GitHubClient {
         per_page: u32,
         max_pages: Option<u32>,
     ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(sv) = severity { check_enum_list("severity", sv, DEPENDABOT_SEVERITIES)?; }
         let mut params = Vec::new();
         if let Some(s) = state { params.push(("state", s.to_string())); }
         if let Some(sv) = severity { params.push(("severity", sv.to_string())); }
@@ -215,16 +1318,36 @@ impl GitHubClient {
         self.get_all_pages_array(&path, params, per_page, max_pages).await
     }
 
+    // Security: Dependabot alerts (org-level)
+    pub async fn list_org_dependabot_alerts(
+        &self,
+        org: &str,
+        state: Option<&str>,   // open, dismissed, fixed
+        severity: Option<&str>, // low, medium, high, critical
+        ecosystem: Option<&str>, // npm, pip, cargo, ...
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(sv) = severity { check_enum_list("severity", sv, DEPENDABOT_SEVERITIES)?; }
+        let mut params = Vec::new();
+        if let Some(s) = state { params.push(("state", s.to_string())); }
+        if let Some(sv) = severity { params.push(("severity", sv.to_string())); }
+        if let Some(e) = ecosystem { params.push(("ecosystem", e.to_string())); }
+        let path = format!("/orgs/{org}/dependabot/alerts");
+        self.get_all_pages_array(&path, params, per_page, max_pages).await
+    }
+
     // Security: Code scanning alerts (repo-level)
     pub async fn list_codescanning_alerts(
         &self,
         owner: &str,
         repo: &str,
         state: Option<&str>, // open, dismissed, fixed
-        severity: Option<&str>, // error, warning, note
+        severity: Option<&str>, // error, warning, note (or critical, high, medium, low)
         per_page: u32,
         max_pages: Option<u32>,
     ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(sv) = severity { check_enum("severity", sv, CODE_SCANNING_SEVERITIES)?; }
         let mut params = Vec::new();
         if let Some(s) = state { params.push(("state", s.to_string())); }
         if let Some(sv) = severity { params.push(("severity", sv.to_string())); }
@@ -242,12 +1365,248 @@ impl GitHubClient {
         per_page: u32,
         max_pages: Option<u32>,
     ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(s) = state { check_enum("state", s, SECRET_ALERT_STATES)?; }
         let mut params = Vec::new();
         if let Some(s) = state { params.push(("state", s.to_string())); }
         if let Some(t) = secret_type { params.push(("secret_type", t.to_string())); }
         let path = format!("/repos/{owner}/{repo}/secret-scanning/alerts");
         self.get_all_pages_array(&path, params, per_page, max_pages).await
     }
+
+    // Security: Secret scanning alerts (org-level)
+    pub async fn list_org_secret_scanning_alerts(
+        &self,
+        org: &str,
+        state: Option<&str>, // open, resolved
+        secret_type: Option<&str>,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        if let Some(s) = state { check_enum("state", s, SECRET_ALERT_STATES)?; }
+        let mut params = Vec::new();
+        if let Some(s) = state { params.push(("state", s.to_string())); }
+        if let Some(t) = secret_type { params.push(("secret_type", t.to_string())); }
+        let path = format!("/orgs/{org}/secret-scanning/alerts");
+        self.get_all_pages_array(&path, params, per_page, max_pages).await
+    }
+
+    // Security: the dependency graph as an SPDX 2.3 SBOM (`{ sbom: { spdxVersion, packages, relationships, ... } }`)
+    pub async fn get_sbom(&self, owner: &str, repo: &str) -> Result<serde_json::Value, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/dependency-graph/sbom");
+        self.get_json(&path, &[]).await
+    }
+
+    // Issues: create an issue
+    pub async fn create_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: Option<&str>,
+        labels: &[String],
+        assignees: &[String],
+        milestone: Option<u64>,
+    ) -> Result<serde_json::Value, ApiError> {
+        if title.trim().is_empty() {
+            return Err(ApiError::InvalidArgument("title must not be empty".into()));
+        }
+        let mut payload = serde_json::json!({ "title": title });
+        if let Some(b) = body { payload["body"] = b.into(); }
+        if !labels.is_empty() { payload["labels"] = labels.into(); }
+        if !assignees.is_empty() { payload["assignees"] = assignees.into(); }
+        if let Some(m) = milestone { payload["milestone"] = m.into(); }
+        let path = format!("/repos/{owner}/{repo}/issues");
+        self.send_json(Method::POST, &path, &payload).await
+    }
+
+    // Issues: add a comment to an issue or pull request
+    pub async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<serde_json::Value, ApiError> {
+        if body.trim().is_empty() {
+            return Err(ApiError::InvalidArgument("comment body must not be empty".into()));
+        }
+        let path = format!("/repos/{owner}/{repo}/issues/{number}/comments");
+        self.send_json(Method::POST, &path, &serde_json::json!({ "body": body })).await
+    }
+
+    // Issues: update state/title/body of an issue
+    pub async fn update_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        state: Option<&str>, // open, closed
+        state_reason: Option<&str>, // completed, not_planned, reopened
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<serde_json::Value, ApiError> {
+        if let Some(s) = state { check_enum("state", s, &["open", "closed"])?; }
+        if let Some(r) = state_reason { check_enum("state_reason", r, ISSUE_STATE_REASONS)?; }
+        let mut payload = serde_json::json!({});
+        if let Some(s) = state { payload["state"] = s.into(); }
+        if let Some(r) = state_reason { payload["state_reason"] = r.into(); }
+        if let Some(t) = title { payload["title"] = t.into(); }
+        if let Some(b) = body { payload["body"] = b.into(); }
+        let path = format!("/repos/{owner}/{repo}/issues/{number}");
+        self.send_json(Method::PATCH, &path, &payload).await
+    }
+
+    // Pulls: merge a pull request
+    pub async fn merge_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        method: &str, // merge, squash, rebase
+        commit_title: Option<&str>,
+        commit_message: Option<&str>,
+        sha: Option<&str>, // expected head SHA; 409 if the branch moved
+    ) -> Result<serde_json::Value, ApiError> {
+        check_enum("merge_method", method, MERGE_METHODS)?;
+        let mut payload = serde_json::json!({ "merge_method": method });
+        if let Some(t) = commit_title { payload["commit_title"] = t.into(); }
+        if let Some(m) = commit_message { payload["commit_message"] = m.into(); }
+        if let Some(s) = sha { payload["sha"] = s.into(); }
+        let path = format!("/repos/{owner}/{repo}/pulls/{number}/merge");
+        self.send_json(Method::PUT, &path, &payload).await
+    }
+
+    // Pulls: SHA of the PR's head commit (what checks and statuses are reported against)
+    pub async fn get_pr_head_sha(&self, owner: &str, repo: &str, number: u64) -> Result<String, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/pulls/{number}");
+        let pr = self.get_json(&path, &[]).await?;
+        pr["head"]["sha"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| ApiError::InvalidArgument(format!("pull request #{number} has no head sha")))
+    }
+
+    // Checks: check runs (GitHub Actions and apps) for a commit; the `check_runs` envelope is unwrapped
+    pub async fn list_check_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        per_page: u32,
+        max_pages: Option<u32>,
+    ) -> Result<Vec<serde_json::Value>, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/commits/{sha}/check-runs");
+        let (runs, _) = self.get_all_pages_envelope(&path, "check_runs", Vec::new(), per_page, max_pages).await?;
+        Ok(runs)
+    }
+
+    // Checks: combined commit status (`state` plus the latest `statuses` entry per context)
+    pub async fn get_combined_status(&self, owner: &str, repo: &str, sha: &str) -> Result<serde_json::Value, ApiError> {
+        let path = format!("/repos/{owner}/{repo}/commits/{sha}/status");
+        self.get_json(&path, &[("per_page", "100".to_string())]).await
+    }
+
+    // Security: update (dismiss/reopen) a Dependabot alert
+    pub async fn update_dependabot_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        state: &str, // dismissed, open
+        dismissed_reason: Option<&str>,
+        dismissed_comment: Option<&str>,
+    ) -> Result<serde_json::Value, ApiError> {
+        check_enum("state", state, DEPENDABOT_ALERT_STATES)?;
+        if let Some(r) = dismissed_reason { check_enum("dismissed_reason", r, DEPENDABOT_DISMISSED_REASONS)?; }
+        if state == "dismissed" && dismissed_reason.is_none() {
+            return Err(ApiError::InvalidArgument("dismissed_reason is required when state is dismissed".into()));
+        }
+        let mut body = serde_json::json!({ "state": state });
+        if let Some(r) = dismissed_reason { body["dismissed_reason"] = r.into(); }
+        if let Some(c) = dismissed_comment { body["dismissed_comment"] = c.into(); }
+        let path = format!("/repos/{owner}/{repo}/dependabot/alerts/{number}");
+        self.send_json(Method::PATCH, &path, &body).await
+    }
+
+    // Security: update (resolve/reopen) a secret scanning alert
+    pub async fn update_secret_scanning_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        state: &str, // resolved, open
+        resolution: Option<&str>,
+    ) -> Result<serde_json::Value, ApiError> {
+        check_enum("state", state, SECRET_ALERT_STATES)?;
+        if let Some(r) = resolution { check_enum("resolution", r, SECRET_ALERT_RESOLUTIONS)?; }
+        if state == "resolved" && resolution.is_none() {
+            return Err(ApiError::InvalidArgument("resolution is required when state is resolved".into()));
+        }
+        let mut body = serde_json::json!({ "state": state });
+        if let Some(r) = resolution { body["resolution"] = r.into(); }
+        let path = format!("/repos/{owner}/{repo}/secret-scanning/alerts/{number}");
+        self.send_json(Method::PATCH, &path, &body).await
+    }
+}
+
+/// Most events the repository events API will return across all pages.
+pub const REPO_EVENTS_CAP: usize = 300;
+
+pub const ISSUE_STATES: &[&str] = &["open", "closed", "all"];
+pub const ISSUE_SORTS: &[&str] = &["created", "updated", "comments"];
+pub const PULL_STATES: &[&str] = &["open", "closed", "all", "merged"];
+pub const PULL_SORTS: &[&str] = &["created", "updated", "popularity", "long-running"];
+pub const ISSUE_STATE_REASONS: &[&str] = &["completed", "not_planned", "reopened"];
+pub const MERGE_METHODS: &[&str] = &["merge", "squash", "rebase"];
+pub const TRAFFIC_PERIODS: &[&str] = &["day", "week"];
+pub const ORG_REPO_SORTS: &[&str] = &["created", "updated", "pushed", "full_name"];
+pub const SORT_DIRECTIONS: &[&str] = &["asc", "desc"];
+pub const MILESTONE_SORTS: &[&str] = &["due_on", "completeness"];
+pub const COLLABORATOR_AFFILIATIONS: &[&str] = &["outside", "direct", "all"];
+pub const COLLABORATOR_PERMISSIONS: &[&str] = &["pull", "triage", "push", "maintain", "admin"];
+pub const DEPENDABOT_SEVERITIES: &[&str] = &["low", "medium", "high", "critical"];
+pub const CODE_SCANNING_SEVERITIES: &[&str] = &["critical", "high", "medium", "low", "warning", "note", "error"];
+pub const WORKFLOW_RUN_STATUSES: &[&str] = &[
+    "completed", "action_required", "cancelled", "failure", "neutral", "skipped", "stale", "success",
+    "timed_out", "in_progress", "queued", "requested", "waiting", "pending",
+];
+pub const DEPENDABOT_ALERT_STATES: &[&str] = &["dismissed", "open"];
+pub const DEPENDABOT_DISMISSED_REASONS: &[&str] = &["fix_started", "inaccurate", "no_bandwidth", "not_used", "tolerable_risk"];
+pub const SECRET_ALERT_STATES: &[&str] = &["open", "resolved"];
+pub const SECRET_ALERT_RESOLUTIONS: &[&str] = &["false_positive", "wont_fix", "revoked", "used_in_tests"];
+
+fn check_enum(name: &str, value: &str, allowed: &[&str]) -> Result<(), ApiError> {
+    if allowed.contains(&value) {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidArgument(format!("{name} '{value}' is not one of: {}", allowed.join(", "))))
+    }
+}
+
+// Comma-separated filters (e.g. severity=high,critical) validate each item.
+fn check_enum_list(name: &str, value: &str, allowed: &[&str]) -> Result<(), ApiError> {
+    value.split(',').try_for_each(|v| check_enum(name, v.trim(), allowed))
+}
+
+// Decode a contents-API object (`content` + `encoding`) into UTF-8 text.
+fn decode_content(v: &serde_json::Value) -> Result<String, ApiError> {
+    use base64::Engine;
+    let content = v["content"].as_str().unwrap_or_default();
+    match v["encoding"].as_str().unwrap_or("base64") {
+        "base64" => {
+            // GitHub wraps base64 at 60 columns
+            let compact: String = content.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(compact)
+                .map_err(|e| ApiError::InvalidArgument(format!("content is not valid base64: {e}")))?;
+            Ok(String::from_utf8_lossy(&bytes).into_owned())
+        }
+        "utf-8" | "utf8" | "" => Ok(content.to_string()),
+        "none" => Err(ApiError::InvalidArgument(
+            "content not included (file too large for the contents API); fetch it from download_url".into(),
+        )),
+        other => Err(ApiError::InvalidArgument(format!("unsupported content encoding '{other}'"))),
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -260,4 +1619,23 @@ pub struct RateLimit {
 pub struct User {
     pub login: String,
     pub id: u64,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub company: Option<String>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+}
+
+/// `User` plus what `current_user_extended` was asked to look up; unrequested parts are left out.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UserProfile {
+    #[serde(flatten)]
+    pub user: User,
+    /// `/user/emails` entries (`email`, `primary`, `verified`, `visibility`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emails: Option<Vec<serde_json::Value>>,
+    /// Logins of the organizations the user belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orgs: Option<Vec<String>>,
 }
@@ -0,0 +1,30 @@
+use opentelemetry::{global, KeyValue};
+use std::time::Duration;
+
+/// Meter name under which the client's instruments are registered.
+pub const METER_NAME: &str = "gh_otco_api";
+
+// Instruments are looked up per response rather than cached so they always bind to the
+// current global meter provider (a no-op until the application installs one).
+pub(crate) fn record_response(method: &str, status: u16, elapsed: Duration, rate_limit_remaining: Option<u64>) {
+    let meter = global::meter(METER_NAME);
+    let attrs = [KeyValue::new("http.request.method", method.to_string()), KeyValue::new("http.response.status_code", i64::from(status))];
+    meter
+        .u64_counter("http.client.requests")
+        .with_description("GitHub API responses by method and status")
+        .init()
+        .add(1, &attrs);
+    meter
+        .f64_histogram("http.client.request.duration")
+        .with_unit("s")
+        .with_description("Time from sending a request to receiving response headers")
+        .init()
+        .record(elapsed.as_secs_f64(), &attrs);
+    if let Some(remaining) = rate_limit_remaining {
+        meter
+            .u64_gauge("github.rate_limit.remaining")
+            .with_description("x-ratelimit-remaining from the latest response")
+            .init()
+            .record(remaining, &[]);
+    }
+}
@@ -53,7 +53,7 @@ async fn org_repos_paginates() {
 
     let client = GitHubClient::new(Some(server.url("").to_string()), None).unwrap();
     let repos = client
-        .list_org_repos("myorg", None, 2, Some(2))
+        .list_org_repos("myorg", None, None, None, 2, Some(2))
         .await
         .unwrap();
     let names: Vec<_> = repos
@@ -65,3 +65,1556 @@ async fn org_repos_paginates() {
     m2.assert();
 }
 
+#[tokio::test]
+async fn first_page_link_header_reports_last_page() {
+    let server = MockServer::start();
+    let link = format!(
+        "<{0}/orgs/myorg/repos?per_page=2&page=2>; rel=\"next\", <{0}/orgs/myorg/repos?per_page=2&page=7>; rel=\"last\"",
+        server.url("")
+    );
+    server.mock(|when, then| {
+        when.method(GET).path("/orgs/myorg/repos").query_param("page", "1");
+        then.status(200).header("link", link.as_str()).json_body(serde_json::json!([{"name":"a"},{"name":"b"}]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    assert_eq!(client.page_info(), None);
+    client.list_org_repos("myorg", None, None, None, 2, Some(1)).await.unwrap();
+    let info = client.page_info().unwrap();
+    assert_eq!(info.last_page, Some(7));
+    assert_eq!(info.pages_fetched, 1);
+    assert_eq!(info.approx_total(), Some(14));
+    assert_eq!(gh_otco_api::parse_last_page(r#"<https://x/y?page=2>; rel="next""#), None);
+}
+
+#[tokio::test]
+async fn repo_milestones_passes_state() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET)
+            .path("/repos/o/r/milestones")
+            .query_param("state", "closed")
+            .query_param("sort", "due_on");
+        then.status(200).json_body(serde_json::json!([{"number":1,"title":"v1","state":"closed"}]));
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), None).unwrap();
+    let milestones = client
+        .list_repo_milestones("o", "r", Some("closed"), Some("due_on"), None, 100, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(milestones.len(), 1);
+    assert_eq!(milestones[0]["title"], "v1");
+    m.assert();
+    // Unknown sort or direction values fail before any request
+    let err = client
+        .list_repo_milestones("o", "r", None, Some("title"), None, 100, Some(1))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, gh_otco_api::ApiError::InvalidArgument(_)));
+    assert!(err.to_string().contains("due_on, completeness"), "{err}");
+    assert!(client.list_repo_milestones("o", "r", None, None, Some("up"), 100, Some(1)).await.is_err());
+    m.assert_hits(1);
+}
+
+#[tokio::test]
+async fn org_dependabot_alerts_paginates() {
+    let server = MockServer::start();
+    let m1 = server.mock(|when, then| {
+        when.method(GET)
+            .path("/orgs/myorg/dependabot/alerts")
+            .query_param("severity", "critical")
+            .query_param("page", "1");
+        then.status(200).json_body(serde_json::json!([
+            {"number":1,"repository":{"full_name":"myorg/a"}},
+            {"number":2,"repository":{"full_name":"myorg/b"}}
+        ]));
+    });
+    let m2 = server.mock(|when, then| {
+        when.method(GET)
+            .path("/orgs/myorg/dependabot/alerts")
+            .query_param("severity", "critical")
+            .query_param("page", "2");
+        then.status(200).json_body(serde_json::json!([]));
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), None).unwrap();
+    let alerts = client
+        .list_org_dependabot_alerts("myorg", None, Some("critical"), None, 2, Some(5))
+        .await
+        .unwrap();
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[1]["repository"]["full_name"], "myorg/b");
+    m1.assert();
+    m2.assert();
+}
+
+#[tokio::test]
+async fn org_secret_scanning_alerts_lists_org_wide() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET)
+            .path("/orgs/myorg/secret-scanning/alerts")
+            .query_param("state", "open");
+        then.status(200).json_body(serde_json::json!([
+            {"number":1,"secret_type":"github_personal_access_token","state":"open","repository":{"full_name":"myorg/a"}},
+            {"number":7,"secret_type":"aws_access_key_id","state":"open","repository":{"full_name":"myorg/b"}}
+        ]));
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), None).unwrap();
+    let alerts = client
+        .list_org_secret_scanning_alerts("myorg", Some("open"), None, 100, Some(1))
+        .await
+        .unwrap();
+    let repos: Vec<_> = alerts
+        .iter()
+        .map(|a| a["repository"]["full_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(repos, vec!["myorg/a", "myorg/b"]);
+    m.assert();
+}
+
+#[tokio::test]
+async fn org_secret_scanning_forbidden_exposes_status() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/orgs/myorg/secret-scanning/alerts");
+        then.status(403).json_body(serde_json::json!({"message":"Must be an organization owner"}));
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), None).unwrap();
+    let err = client
+        .list_org_secret_scanning_alerts("myorg", None, None, 100, Some(1))
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(403));
+}
+
+#[tokio::test]
+async fn sbom_returns_spdx_document() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/dependency-graph/sbom");
+        then.status(200).json_body(serde_json::json!({"sbom": {
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "spdxVersion": "SPDX-2.3",
+            "packages": [{"name": "serde", "versionInfo": "1.0.0"}]
+        }}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let sbom = client.get_sbom("o", "r").await.unwrap();
+    assert_eq!(sbom["sbom"]["spdxVersion"], "SPDX-2.3");
+    assert_eq!(sbom["sbom"]["packages"][0]["name"], "serde");
+    m.assert();
+}
+
+#[tokio::test]
+async fn create_issue_posts_title_and_labels() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(POST)
+            .path("/repos/o/r/issues")
+            .json_body(serde_json::json!({
+                "title": "Nightly build failed",
+                "body": "see logs",
+                "labels": ["bug", "ci"],
+                "milestone": 3
+            }));
+        then.status(201).json_body(serde_json::json!({"number": 42, "title": "Nightly build failed"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let issue = client
+        .create_issue("o", "r", "Nightly build failed", Some("see logs"), &["bug".into(), "ci".into()], &[], Some(3))
+        .await
+        .unwrap();
+    assert_eq!(issue["number"], 42);
+    m.assert();
+}
+
+#[tokio::test]
+async fn create_issue_comment_posts_body() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(POST)
+            .path("/repos/o/r/issues/7/comments")
+            .json_body(serde_json::json!({"body": "Fixed in #8"}));
+        then.status(201).json_body(serde_json::json!({
+            "id": 99,
+            "body": "Fixed in #8",
+            "html_url": "https://github.com/o/r/issues/7#issuecomment-99"
+        }));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let comment = client.create_issue_comment("o", "r", 7, "Fixed in #8").await.unwrap();
+    assert_eq!(comment["id"], 99);
+    assert_eq!(comment["html_url"], "https://github.com/o/r/issues/7#issuecomment-99");
+    m.assert();
+}
+
+#[tokio::test]
+async fn update_issue_patches_state_and_reason() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(httpmock::Method::PATCH)
+            .path("/repos/o/r/issues/7")
+            .json_body(serde_json::json!({"state": "closed", "state_reason": "not_planned"}));
+        then.status(200).json_body(serde_json::json!({"number": 7, "state": "closed", "state_reason": "not_planned"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let issue = client
+        .update_issue("o", "r", 7, Some("closed"), Some("not_planned"), None, None)
+        .await
+        .unwrap();
+    assert_eq!(issue["state_reason"], "not_planned");
+    m.assert();
+
+    let err = client.update_issue("o", "r", 7, Some("closed"), Some("wontfix"), None, None).await.unwrap_err();
+    assert!(err.to_string().contains("completed, not_planned"), "{err}");
+}
+
+#[tokio::test]
+async fn merge_pull_request_squash() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(PUT)
+            .path("/repos/o/r/pulls/12/merge")
+            .json_body(serde_json::json!({"merge_method": "squash", "commit_title": "Add feature (#12)", "sha": "abc123"}));
+        then.status(200).json_body(serde_json::json!({"sha": "def456", "merged": true, "message": "Pull Request successfully merged"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let res = client
+        .merge_pull_request("o", "r", 12, "squash", Some("Add feature (#12)"), None, Some("abc123"))
+        .await
+        .unwrap();
+    assert_eq!(res["merged"], true);
+    m.assert();
+}
+
+#[tokio::test]
+async fn merge_pull_request_sha_conflict_exposes_409() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(PUT).path("/repos/o/r/pulls/12/merge");
+        then.status(409).json_body(serde_json::json!({"message": "Head branch was modified. Review and try the merge again."}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let err = client
+        .merge_pull_request("o", "r", 12, "merge", None, None, Some("stale"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(409));
+}
+
+#[tokio::test]
+async fn dismiss_dependabot_alert_sends_patch_body() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(httpmock::Method::PATCH)
+            .path("/repos/o/r/dependabot/alerts/5")
+            .json_body(serde_json::json!({
+                "state": "dismissed",
+                "dismissed_reason": "tolerable_risk",
+                "dismissed_comment": "internal only"
+            }));
+        then.status(200).json_body(serde_json::json!({"number":5,"state":"dismissed"}));
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), None).unwrap();
+    let alert = client
+        .update_dependabot_alert("o", "r", 5, "dismissed", Some("tolerable_risk"), Some("internal only"))
+        .await
+        .unwrap();
+    assert_eq!(alert["state"], "dismissed");
+    m.assert();
+}
+
+#[tokio::test]
+async fn dismiss_dependabot_alert_rejects_unknown_reason() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(httpmock::Method::PATCH).path("/repos/o/r/dependabot/alerts/5");
+        then.status(200);
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), None).unwrap();
+    let err = client
+        .update_dependabot_alert("o", "r", 5, "dismissed", Some("meh"), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, gh_otco_api::ApiError::InvalidArgument(_)));
+    m.assert_hits(0);
+}
+
+#[tokio::test]
+async fn resolve_secret_alert_sends_resolution() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(httpmock::Method::PATCH)
+            .path("/repos/o/r/secret-scanning/alerts/3")
+            .json_body(serde_json::json!({"state": "resolved", "resolution": "revoked"}));
+        then.status(200).json_body(serde_json::json!({
+            "number": 3,
+            "state": "resolved",
+            "resolution": "revoked",
+            "resolved_by": {"login": "octo"}
+        }));
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), None).unwrap();
+    let alert = client
+        .update_secret_scanning_alert("o", "r", 3, "resolved", Some("revoked"))
+        .await
+        .unwrap();
+    assert_eq!(alert["resolution"], "revoked");
+    assert_eq!(alert["resolved_by"]["login"], "octo");
+    m.assert();
+}
+
+#[tokio::test]
+async fn resolve_secret_alert_rejects_unknown_resolution() {
+    let client = GitHubClient::new(Some("http://127.0.0.1:9".into()), None).unwrap();
+    let err = client
+        .update_secret_scanning_alert("o", "r", 3, "resolved", Some("fixed"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("false_positive"));
+}
+
+#[tokio::test]
+async fn invalid_enum_filters_fail_before_request() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.any_request();
+        then.status(422);
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let err = client
+        .list_repo_issues("o", "r", Some("opened"), None, None, None, None, None, None, None, None, 100, Some(1))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("open, closed, all"), "{err}");
+    let err = client
+        .list_dependabot_alerts("o", "r", None, Some("high,severe"), 100, Some(1))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, gh_otco_api::ApiError::InvalidArgument(_)));
+    assert!(client.list_codescanning_alerts("o", "r", None, Some("fatal"), 100, Some(1)).await.is_err());
+    assert!(client.list_secret_scanning_alerts("o", "r", Some("closed"), None, 100, Some(1)).await.is_err());
+    assert!(client.list_repo_workflow_runs("o", "r", None, Some("running"), None, None, None, None, 100, Some(1)).await.is_err());
+    m.assert_hits(0);
+}
+
+#[tokio::test]
+async fn explain_mode_describes_request_without_sending() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/issues");
+        then.status(200).json_body(serde_json::json!([]));
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), Some("testtoken".into()))
+        .unwrap()
+        .with_explain(true);
+    let err = client
+        .list_repo_issues("o", "r", Some("open"), None, None, None, None, None, None, None, None, 50, Some(1))
+        .await
+        .unwrap_err();
+    let plan = match err {
+        gh_otco_api::ApiError::Explain(plan) => plan,
+        other => panic!("expected explain plan, got {other}"),
+    };
+    assert_eq!(plan.method, "GET");
+    assert!(plan.url.contains("/repos/o/r/issues?state=open&per_page=50&page=1"));
+    assert!(plan.headers.iter().any(|(k, v)| k == "authorization" && v == "<redacted>"));
+    assert!(!plan.to_string().contains("testtoken"));
+    m.assert_hits(0);
+}
+
+#[tokio::test]
+async fn clients_share_one_http_client() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/user").header("x-pool", "shared");
+        then.status(200).json_body(serde_json::json!({"login":"octo","id":1}));
+    });
+    // A default header marks requests made through the shared reqwest client
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-pool", reqwest::header::HeaderValue::from_static("shared"));
+    let http = reqwest::Client::builder().default_headers(headers).build().unwrap();
+    let a = GitHubClient::with_client(http.clone(), Some(server.url("")), None).unwrap();
+    let b = GitHubClient::with_client(http, Some(server.url("")), Some("t".into())).unwrap();
+    a.current_user().await.unwrap();
+    b.current_user().await.unwrap();
+    m.assert_hits(2);
+}
+
+#[tokio::test]
+async fn redirect_to_other_host_drops_authorization() {
+    let api = MockServer::start();
+    let storage = MockServer::start();
+    let download = storage.mock(|when, then| {
+        when.method(GET).path("/blob").matches(|req| {
+            !req.headers.iter().flatten().any(|(k, _)| k.eq_ignore_ascii_case("authorization"))
+        });
+        then.status(200).json_body(serde_json::json!({"login":"octo","id":1}));
+    });
+    let redirect = api.mock(|when, then| {
+        when.method(GET).path("/user").header("authorization", "Bearer testtoken");
+        then.status(302).header("location", storage.url("/blob"));
+    });
+    let client = GitHubClient::new(Some(api.url("")), Some("testtoken".into())).unwrap();
+    let user = client.current_user().await.unwrap();
+    assert_eq!(user.login, "octo");
+    redirect.assert();
+    download.assert();
+}
+
+#[tokio::test]
+async fn redirects_are_bounded() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/user");
+        then.status(302).header("location", server.url("/user"));
+    });
+    let http = gh_otco_api::HttpClientBuilder::new().max_redirects(2).build().unwrap();
+    let client = GitHubClient::with_client(http, Some(server.url("")), None).unwrap();
+    let err = client.current_user().await.unwrap_err();
+    assert!(format!("{err:?}").contains("too many redirects"), "{err:?}");
+}
+
+// Collects formatted tracing output so tests can inspect logged events.
+#[derive(Clone, Default)]
+struct LogBuffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn http_debug_logs_request_and_response_without_token() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/user");
+        then.status(200).header("x-ratelimit-remaining", "4999").json_body(serde_json::json!({"login":"octo","id":1}));
+    });
+    let logs = LogBuffer::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let client = GitHubClient::new(Some(server.url("")), Some("testtoken".into())).unwrap();
+    client.current_user().await.unwrap();
+
+    let out = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(out.contains("http request") && out.contains("method=GET"), "{out}");
+    assert!(out.contains("/user"), "{out}");
+    assert!(out.contains("status=200") && out.contains("ratelimit_remaining=\"4999\""), "{out}");
+    assert!(out.contains("authorization: <redacted>"), "{out}");
+    assert!(!out.contains("testtoken"), "{out}");
+}
+
+#[tokio::test]
+async fn repo_languages_returns_object() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/languages");
+        then.status(200).json_body(serde_json::json!({"Rust": 9000, "Shell": 1000}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let langs = client.get_repo_languages("o", "r").await.unwrap();
+    assert_eq!(langs["Rust"], 9000);
+    m.assert();
+}
+
+#[tokio::test]
+async fn repo_readme_decodes_base64() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/readme").query_param("ref", "v1");
+        // "# Hello\nWorld\n" wrapped across lines as GitHub does
+        then.status(200).json_body(serde_json::json!({"encoding": "base64", "content": "IyBIZWxs\nbwpXb3JsZAo=\n"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let text = client.get_repo_readme("o", "r", Some("v1")).await.unwrap();
+    assert_eq!(text, "# Hello\nWorld\n");
+    m.assert();
+}
+
+#[tokio::test]
+async fn repo_readme_unsupported_encoding_errors() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/readme");
+        then.status(200).json_body(serde_json::json!({"encoding": "none", "content": ""}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let err = client.get_repo_readme("o", "r", None).await.unwrap_err();
+    assert!(err.to_string().contains("download_url"), "{err}");
+}
+
+#[tokio::test]
+async fn repo_forks_paginates_with_sort() {
+    let server = MockServer::start();
+    let m1 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/forks").query_param("sort", "stargazers").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!([{"full_name":"a/r","owner":{"login":"a"}}]));
+    });
+    let m2 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/forks").query_param("sort", "stargazers").query_param("page", "2");
+        then.status(200).json_body(serde_json::json!([{"full_name":"b/r","owner":{"login":"b"}}]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let forks = client.list_repo_forks("o", "r", Some("stargazers"), 1, Some(2)).await.unwrap();
+    let owners: Vec<_> = forks.iter().map(|f| f["owner"]["login"].as_str().unwrap()).collect();
+    assert_eq!(owners, vec!["a", "b"]);
+    m1.assert();
+    m2.assert();
+}
+
+#[tokio::test]
+async fn repo_stargazers_paginates() {
+    let server = MockServer::start();
+    let m1 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/stargazers").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!([{"login":"octo"},{"login":"cat"}]));
+    });
+    let m2 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/stargazers").query_param("page", "2");
+        then.status(200).json_body(serde_json::json!([]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let users = client.list_repo_stargazers("o", "r", 2, Some(5)).await.unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[1]["login"], "cat");
+    m1.assert();
+    m2.assert();
+}
+
+#[tokio::test]
+async fn repo_collaborators_passes_affiliation() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET)
+            .path("/repos/o/r/collaborators")
+            .query_param("affiliation", "outside")
+            .query_param("permission", "admin");
+        then.status(200).json_body(serde_json::json!([
+            {"login": "contractor", "role_name": "admin", "permissions": {"admin": true, "push": true, "pull": true}}
+        ]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let users = client
+        .list_repo_collaborators("o", "r", Some("outside"), Some("admin"), 100, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(users[0]["permissions"]["admin"], true);
+    m.assert();
+}
+
+#[tokio::test]
+async fn notifications_send_all_and_participating() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET)
+            .path("/notifications")
+            .query_param("all", "true")
+            .query_param("participating", "true")
+            .query_param("since", "2024-01-01T00:00:00Z");
+        then.status(200).json_body(serde_json::json!([
+            {"reason": "mention", "unread": true, "subject": {"title": "Fix it", "type": "Issue"}, "repository": {"full_name": "o/r"}}
+        ]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let items = client
+        .list_notifications(true, true, Some("2024-01-01T00:00:00Z"), 50, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(items[0]["subject"]["title"], "Fix it");
+    m.assert();
+}
+
+#[tokio::test]
+async fn org_get_returns_profile() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/orgs/acme");
+        then.status(200).json_body(serde_json::json!({
+            "login": "acme", "public_repos": 12, "total_private_repos": 3,
+            "plan": {"name": "team", "filled_seats": 8},
+            "two_factor_requirement_enabled": true,
+            "secret_scanning_enabled_for_new_repositories": false
+        }));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let org = client.get_org("acme").await.unwrap();
+    assert_eq!(org["login"], "acme");
+    assert_eq!(org["plan"]["name"], "team");
+    assert_eq!(org["plan"]["filled_seats"], 8);
+    assert_eq!(org["public_repos"], 12);
+    assert_eq!(org["two_factor_requirement_enabled"], true);
+    m.assert();
+}
+
+#[tokio::test]
+async fn org_get_unknown_is_404() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/orgs/nope");
+        then.status(404).json_body(serde_json::json!({"message": "Not Found"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let err = client.get_org("nope").await.unwrap_err();
+    assert_eq!(err.status(), Some(404));
+}
+
+#[tokio::test]
+async fn repo_deployments_filters_by_environment() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/deployments").query_param("environment", "production");
+        then.status(200).json_body(serde_json::json!([
+            {"id": 1, "environment": "production", "ref": "main", "created_at": "2024-05-01T00:00:00Z"}
+        ]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let deployments = client.list_repo_deployments("o", "r", Some("production"), 100, Some(1)).await.unwrap();
+    assert_eq!(deployments.len(), 1);
+    assert_eq!(deployments[0]["ref"], "main");
+    m.assert();
+}
+
+#[tokio::test]
+async fn repo_environments_unwraps_envelope() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/environments").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!({
+            "total_count": 2,
+            "environments": [
+                {"name": "staging", "protection_rules": []},
+                {"name": "production", "protection_rules": [{"type": "required_reviewers"}]}
+            ]
+        }));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let envs = client.list_repo_environments("o", "r", 100, Some(1)).await.unwrap();
+    assert_eq!(envs.len(), 2);
+    assert_eq!(envs[1]["name"], "production");
+    assert_eq!(envs[1]["protection_rules"][0]["type"], "required_reviewers");
+    m.assert();
+}
+
+#[tokio::test]
+async fn token_scopes_reads_oauth_header() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/user");
+        then.status(200).header("x-oauth-scopes", "repo, read:org").json_body(serde_json::json!({"login": "octo", "id": 1}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), Some("t".into())).unwrap();
+    assert_eq!(client.token_scopes().await.unwrap(), Some(vec!["repo".to_string(), "read:org".to_string()]));
+}
+
+#[tokio::test]
+async fn repo_events_stop_at_cap() {
+    let server = MockServer::start();
+    let page = serde_json::Value::Array((0..100).map(|i| serde_json::json!({"id": i, "type": "PushEvent"})).collect());
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/events").query_param("per_page", "100");
+        then.status(200).json_body(page.clone());
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    // Asking for everything still stops after 300 events (3 pages of 100).
+    let events = client.list_repo_events("o", "r", 100, Some(u32::MAX)).await.unwrap();
+    assert_eq!(events.len(), gh_otco_api::REPO_EVENTS_CAP);
+    m.assert_hits(3);
+    let unbounded = client.list_repo_events("o", "r", 100, None).await.unwrap();
+    assert_eq!(unbounded.len(), 300);
+}
+
+#[tokio::test]
+async fn extra_headers_are_sent_and_authorization_is_refused() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/rate_limit").header("x-custom", "42").header("accept", "application/vnd.github.preview+json");
+        then.status(200).json_body(serde_json::json!({"rate": {}, "resources": {}}));
+    });
+    let extra = vec![
+        ("X-Custom".to_string(), "42".to_string()),
+        ("Accept".to_string(), "application/vnd.github.preview+json".to_string()),
+    ];
+    let client = GitHubClient::new(Some(server.url("")), Some("t".into())).unwrap().with_extra_headers(&extra).unwrap();
+    client.rate_limit().await.unwrap();
+    m.assert();
+
+    let auth = vec![("authorization".to_string(), "Bearer other".to_string())];
+    let err = GitHubClient::new(Some(server.url("")), None).unwrap().with_extra_headers(&auth).err().unwrap();
+    assert!(matches!(err, gh_otco_api::ApiError::InvalidArgument(ref msg) if msg.contains("Authorization")), "{err}");
+}
+
+#[cfg(feature = "otel")]
+#[tokio::test(flavor = "multi_thread")]
+async fn otel_counts_requests_by_status() {
+    use opentelemetry_sdk::metrics::{data::Sum, PeriodicReader, SdkMeterProvider};
+    use opentelemetry_sdk::testing::metrics::InMemoryMetricsExporter;
+
+    let exporter = InMemoryMetricsExporter::default();
+    let reader = PeriodicReader::builder(exporter.clone(), opentelemetry_sdk::runtime::Tokio).build();
+    let provider = SdkMeterProvider::builder().with_reader(reader).build();
+    opentelemetry::global::set_meter_provider(provider.clone());
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/user");
+        then.status(418);
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    assert!(client.current_user().await.is_err());
+
+    provider.force_flush().unwrap();
+    let metrics = exporter.get_finished_metrics().unwrap();
+    let requests = metrics
+        .iter()
+        .flat_map(|rm| &rm.scope_metrics)
+        .filter(|sm| sm.scope.name == gh_otco_api::METER_NAME)
+        .flat_map(|sm| &sm.metrics)
+        .find(|m| m.name == "http.client.requests")
+        .expect("request counter exported");
+    let sum = requests.data.as_any().downcast_ref::<Sum<u64>>().unwrap();
+    let teapots: u64 = sum
+        .data_points
+        .iter()
+        .filter(|dp| dp.attributes.iter().any(|kv| kv.key.as_str() == "http.response.status_code" && kv.value == opentelemetry::Value::I64(418)))
+        .map(|dp| dp.value)
+        .sum();
+    assert_eq!(teapots, 1);
+}
+
+#[tokio::test]
+async fn pagination_is_unbounded_without_max_pages() {
+    let server = MockServer::start();
+    let full = server.mock(|when, then| {
+        when.method(GET).path("/orgs/o/repos").matches(|req| {
+            let page = req.query_params.as_ref().and_then(|q| q.iter().find(|(k, _)| k == "page")).map(|(_, v)| v.parse::<u32>().unwrap());
+            page.is_some_and(|p| p <= 12)
+        });
+        then.status(200).json_body(serde_json::json!([{"id": 1}]));
+    });
+    let empty = server.mock(|when, then| {
+        when.method(GET).path("/orgs/o/repos").query_param("page", "13");
+        then.status(200).json_body(serde_json::json!([]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let repos = client.list_org_repos("o", None, None, None, 1, None).await.unwrap();
+    assert_eq!(repos.len(), 12);
+    full.assert_hits(12);
+    empty.assert();
+}
+
+#[tokio::test]
+async fn max_items_stops_pagination_early() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/orgs/o/repos");
+        then.status(200).json_body(serde_json::json!([{"id": 1}, {"id": 2}]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap().with_max_items(Some(3));
+    let repos = client.list_org_repos("o", None, None, None, 2, None).await.unwrap();
+    assert_eq!(repos.len(), 3);
+    m.assert_hits(2);
+}
+
+#[tokio::test]
+async fn gzip_response_is_decoded() {
+    use std::io::Write;
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    gz.write_all(br#"[{"id": 1, "name": "compressed"}]"#).unwrap();
+    let body = gz.finish().unwrap();
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/orgs/o/repos").matches(|req| {
+            req.headers.as_ref().is_some_and(|h| h.iter().any(|(k, v)| k == "accept-encoding" && v.contains("gzip")))
+        });
+        then.status(200).header("content-type", "application/json").header("content-encoding", "gzip").body(body.clone());
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let repos = client.list_org_repos("o", None, None, None, 100, Some(1)).await.unwrap();
+    assert_eq!(repos[0]["name"], "compressed");
+    m.assert();
+}
+
+#[tokio::test]
+async fn compression_can_be_disabled() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/user").matches(|req| {
+            !req.headers.as_ref().is_some_and(|h| h.iter().any(|(k, _)| k == "accept-encoding"))
+        });
+        then.status(200).json_body(serde_json::json!({"login": "octo", "id": 1}));
+    });
+    let http = gh_otco_api::HttpClientBuilder::new().compression(false).build().unwrap();
+    let client = GitHubClient::with_client(http, Some(server.url("")), None).unwrap();
+    client.current_user().await.unwrap();
+    m.assert();
+}
+
+const ISSUES_QUERY: &str = "query($owner: String!, $name: String!, $cursor: String) { repository(owner: $owner, name: $name) { issues(first: 2, after: $cursor) { nodes { number } pageInfo { hasNextPage endCursor } } } }";
+
+#[tokio::test]
+async fn graphql_paginate_follows_end_cursor() {
+    let server = MockServer::start();
+    let first = server.mock(|when, then| {
+        when.method(POST).path("/graphql").matches(|req| !String::from_utf8_lossy(req.body.as_deref().unwrap_or_default()).contains("\"cursor\""));
+        then.status(200).json_body(serde_json::json!({"data": {"repository": {"issues": {
+            "nodes": [{"number": 1}, {"number": 2}], "pageInfo": {"hasNextPage": true, "endCursor": "c1"}
+        }}}}));
+    });
+    let second = server.mock(|when, then| {
+        when.method(POST).path("/graphql").json_body_partial(r#"{"variables": {"owner": "o", "cursor": "c1"}}"#);
+        then.status(200).json_body(serde_json::json!({"data": {"repository": {"issues": {
+            "nodes": [{"number": 3}], "pageInfo": {"hasNextPage": false, "endCursor": "c2"}
+        }}}}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), Some("t".into())).unwrap();
+    let vars = serde_json::json!({"owner": "o", "name": "r"});
+    let nodes = client.graphql_paginate(ISSUES_QUERY, vars.clone(), "repository.issues", None).await.unwrap();
+    let numbers: Vec<u64> = nodes.iter().map(|n| n["number"].as_u64().unwrap()).collect();
+    assert_eq!(numbers, [1, 2, 3]);
+    first.assert();
+    second.assert();
+
+    let capped = client.graphql_paginate(ISSUES_QUERY, vars, "repository.issues", Some(1)).await.unwrap();
+    assert_eq!(capped.len(), 2);
+    first.assert_hits(2);
+}
+
+#[tokio::test]
+async fn graphql_errors_fail_the_call() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST).path("/graphql");
+        then.status(200).json_body(serde_json::json!({"data": null, "errors": [{"message": "Could not resolve to a Repository"}]}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), Some("t".into())).unwrap();
+    let err = client.graphql("query { viewer { login } }", serde_json::json!({})).await.unwrap_err();
+    assert_eq!(err.to_string(), "graphql error: Could not resolve to a Repository");
+}
+
+#[tokio::test]
+async fn workflow_runs_page_through_envelope_with_total_count() {
+    let server = MockServer::start();
+    let link = format!("<{}/repos/o/r/actions/runs?per_page=2&page=2>; rel=\"last\"", server.url(""));
+    let p1 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/actions/runs").query_param("page", "1").query_param("branch", "main");
+        then.status(200).header("link", link.as_str()).json_body(serde_json::json!({
+            "total_count": 3,
+            "workflow_runs": [{"id": 1}, {"id": 2}]
+        }));
+    });
+    let p2 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/actions/runs").query_param("page", "2");
+        then.status(200).json_body(serde_json::json!({"total_count": 3, "workflow_runs": [{"id": 3}]}));
+    });
+    // Never reached: paging stops once total_count items have arrived
+    let p3 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/actions/runs").query_param("page", "3");
+        then.status(200).json_body(serde_json::json!({"total_count": 3, "workflow_runs": []}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let runs = client.list_repo_workflow_runs("o", "r", Some("main"), None, None, None, None, None, 2, None).await.unwrap();
+    let ids: Vec<_> = runs.iter().map(|r| r["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+    let info = client.page_info().unwrap();
+    assert_eq!((info.total_count, info.pages_fetched, info.last_page), (Some(3), 2, Some(2)));
+    p1.assert();
+    p2.assert();
+    p3.assert_hits(0);
+}
+
+#[tokio::test]
+async fn workflows_unwrap_envelope() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/actions/workflows").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!({"total_count": 1, "workflows": [{"id": 7, "name": "CI"}]}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let workflows = client.list_repo_workflows("o", "r", 100, Some(1)).await.unwrap();
+    assert_eq!(workflows, vec![serde_json::json!({"id": 7, "name": "CI"})]);
+    assert_eq!(client.page_info().unwrap().total_count, Some(1));
+}
+
+#[tokio::test]
+async fn action_secrets_unwrap_envelope() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/actions/secrets").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!({
+            "total_count": 2,
+            "secrets": [
+                {"name": "DEPLOY_KEY", "created_at": "2024-01-10T10:00:00Z", "updated_at": "2024-03-01T09:00:00Z"},
+                {"name": "NPM_TOKEN", "created_at": "2024-02-01T08:00:00Z", "updated_at": "2024-02-01T08:00:00Z"}
+            ]
+        }));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let secrets = client.list_repo_action_secrets("o", "r", 100, Some(1)).await.unwrap();
+    let names: Vec<&str> = secrets.iter().filter_map(|s| s["name"].as_str()).collect();
+    assert_eq!(names, ["DEPLOY_KEY", "NPM_TOKEN"]);
+    assert_eq!(client.page_info().unwrap().total_count, Some(2));
+}
+
+#[tokio::test]
+async fn action_variables_unwrap_envelope() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/actions/variables").query_param("per_page", "30").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!({
+            "total_count": 2,
+            "variables": [
+                {"name": "REGION", "value": "eu-west-1", "created_at": "2024-01-10T10:00:00Z"},
+                {"name": "NODE_VERSION", "value": "20", "created_at": "2024-02-01T08:00:00Z"}
+            ]
+        }));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let variables = client.list_repo_action_variables("o", "r", 30, Some(1)).await.unwrap();
+    assert_eq!(variables.len(), 2);
+    assert_eq!(variables[1]["value"], "20");
+}
+
+#[tokio::test]
+async fn gists_list_authenticated_or_by_user() {
+    let server = MockServer::start();
+    let mine = server.mock(|when, then| {
+        when.method(GET).path("/gists").query_param("since", "2024-01-01T00:00:00Z");
+        then.status(200).json_body(serde_json::json!([{"id": "a1", "public": false, "files": {"notes.md": {}}}]));
+    });
+    let theirs = server.mock(|when, then| {
+        when.method(GET).path("/users/octocat/gists");
+        then.status(200).json_body(serde_json::json!([{"id": "b2", "public": true, "files": {"a.rs": {}, "b.rs": {}}}]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), Some("t".into())).unwrap();
+    let gists = client.list_gists(None, Some("2024-01-01T00:00:00Z"), 100, Some(1)).await.unwrap();
+    assert_eq!(gists[0]["id"], "a1");
+    let gists = client.list_gists(Some("octocat"), None, 100, Some(1)).await.unwrap();
+    assert_eq!(gists[0]["id"], "b2");
+    mine.assert();
+    theirs.assert();
+}
+
+#[tokio::test]
+async fn api_version_override_is_sent_and_validated() {
+    let server = MockServer::start();
+    let custom = server.mock(|when, then| {
+        when.method(GET).path("/user").header("x-github-api-version", "2026-03-10");
+        then.status(200).json_body(serde_json::json!({"login": "octo", "id": 1}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap().with_api_version("2026-03-10".into()).unwrap();
+    client.current_user().await.unwrap();
+    custom.assert();
+
+    let default = server.mock(|when, then| {
+        when.method(GET).path("/rate_limit").header("x-github-api-version", gh_otco_api::DEFAULT_API_VERSION);
+        then.status(200).json_body(serde_json::json!({"rate": {}, "resources": {}}));
+    });
+    GitHubClient::new(Some(server.url("")), None).unwrap().rate_limit().await.unwrap();
+    default.assert();
+
+    for bad in ["2026-3-10", "latest", "2026/03/10", "2026-03-10x"] {
+        let err = GitHubClient::new(None, None).unwrap().with_api_version(bad.into()).err().unwrap();
+        assert!(err.to_string().contains("expected YYYY-MM-DD"), "{bad}: {err}");
+    }
+}
+
+#[tokio::test]
+async fn repo_topics_unwraps_names() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/topics");
+        then.status(200).json_body(serde_json::json!({"names": ["rust", "cli", "github"]}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    assert_eq!(client.get_repo_topics("o", "r").await.unwrap(), ["rust", "cli", "github"]);
+    m.assert();
+}
+
+#[tokio::test]
+async fn unauthorized_is_a_typed_error_naming_the_host() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/user");
+        then.status(401).json_body(serde_json::json!({"message": "Bad credentials"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), Some("expired".into())).unwrap();
+    let err = client.current_user().await.unwrap_err();
+    assert!(matches!(&err, gh_otco_api::ApiError::Unauthorized { host } if host == "127.0.0.1"), "{err:?}");
+    assert_eq!(err.status(), Some(401));
+}
+
+#[tokio::test]
+async fn compare_commits_returns_comparison() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/compare/v1.0...main");
+        then.status(200).json_body(serde_json::json!({
+            "status": "ahead", "ahead_by": 2, "behind_by": 0, "total_commits": 2,
+            "commits": [{"sha": "a1"}, {"sha": "b2"}]
+        }));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let cmp = client.compare_commits("o", "r", "v1.0", "main").await.unwrap();
+    assert_eq!(cmp["status"], "ahead");
+    assert_eq!(cmp["ahead_by"], 2);
+    assert_eq!(cmp["behind_by"], 0);
+    assert_eq!(cmp["total_commits"], 2);
+    assert_eq!(cmp["commits"].as_array().unwrap().len(), 2);
+    m.assert();
+}
+
+#[tokio::test]
+async fn wait_on_rate_limit_resumes_paging_after_reset() {
+    let server = MockServer::start();
+    let reset = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs() + 2;
+    let first = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/issues").query_param("page", "1");
+        then.status(200)
+            .header("x-ratelimit-remaining", "0")
+            .header("x-ratelimit-reset", reset.to_string())
+            .json_body(serde_json::json!([{"number": 1}]));
+    });
+    let second = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/issues").query_param("page", "2");
+        then.status(200).header("x-ratelimit-remaining", "4999").json_body(serde_json::json!([]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap().with_wait_on_rate_limit(true);
+    let started = std::time::Instant::now();
+    let issues = client.list_repo_issues("o", "r", None, None, None, None, None, None, None, None, None, 1, None).await.unwrap();
+    assert_eq!(issues.len(), 1);
+    // The second page waited for the reset instead of going out immediately
+    assert!(started.elapsed() >= std::time::Duration::from_millis(500));
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    first.assert();
+    second.assert();
+}
+
+#[tokio::test]
+async fn wait_on_rate_limit_retries_a_rejected_request() {
+    let server = MockServer::start();
+    let reset = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs() + 2;
+    let mut limited = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/topics");
+        then.status(403)
+            .header("x-ratelimit-remaining", "0")
+            .header("x-ratelimit-reset", reset.to_string())
+            .json_body(serde_json::json!({"message": "API rate limit exceeded"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    assert_eq!(client.get_repo_topics("o", "r").await.unwrap_err().status(), Some(403));
+    let waiting = client.with_wait_on_rate_limit(true);
+    let call = tokio::spawn(async move { waiting.get_repo_topics("o", "r").await });
+    // Swap in the post-reset response once the first attempt has been rejected
+    while limited.hits() < 2 {
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    limited.delete();
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/topics");
+        then.status(200).json_body(serde_json::json!({"names": ["rust"]}));
+    });
+    assert_eq!(call.await.unwrap().unwrap(), ["rust"]);
+}
+
+#[tokio::test]
+async fn builder_sends_configured_headers() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET)
+            .path("/repos/o/r/topics")
+            .header("user-agent", "release-bot/1.0")
+            .header("accept", "application/vnd.github.mercy-preview+json")
+            .header("x-github-api-version", "2026-03-10")
+            .header("authorization", "Bearer t0ken");
+        then.status(200).json_body(serde_json::json!({"names": ["rust"]}));
+    });
+    let client = GitHubClient::builder()
+        .base_url(server.url(""))
+        .token("t0ken")
+        .user_agent("release-bot/1.0")
+        .accept("application/vnd.github.mercy-preview+json")
+        .api_version("2026-03-10")
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .unwrap();
+    assert_eq!(client.get_repo_topics("o", "r").await.unwrap(), ["rust"]);
+    m.assert();
+}
+
+#[tokio::test]
+async fn builder_defaults_match_new_and_retry_resends_gets() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/topics").header("user-agent", gh_otco_api::DEFAULT_USER_AGENT);
+        then.status(503);
+    });
+    let plain = GitHubClient::new(Some(server.url("")), None).unwrap();
+    assert_eq!(plain.get_repo_topics("o", "r").await.unwrap_err().status(), Some(503));
+    m.assert_hits(1);
+    let retrying = GitHubClient::builder().base_url(server.url("")).retry(2).build().unwrap();
+    assert_eq!(retrying.get_repo_topics("o", "r").await.unwrap_err().status(), Some(503));
+    m.assert_hits(4);
+    assert!(GitHubClient::builder().user_agent("bad\nagent").build().is_err());
+}
+
+#[tokio::test]
+async fn meta_and_zen_handle_json_and_text_bodies() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/meta");
+        then.status(200).json_body(serde_json::json!({"verifiable_password_authentication": false, "installed_version": "3.14.0", "api": ["192.0.2.0/24"]}));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/zen");
+        then.status(200).header("content-type", "text/plain;charset=utf-8").body("Keep it logically awesome.");
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let meta = client.meta().await.unwrap();
+    assert_eq!(meta["installed_version"], "3.14.0");
+    assert_eq!(meta["verifiable_password_authentication"], false);
+    assert_eq!(client.zen().await.unwrap(), "Keep it logically awesome.");
+}
+
+#[tokio::test]
+async fn exhausted_rate_limit_is_a_typed_error() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/topics");
+        then.status(403).header("x-ratelimit-remaining", "0").header("x-ratelimit-reset", "1700000000").json_body(serde_json::json!({"message": "API rate limit exceeded"}));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/readme");
+        then.status(403).header("x-ratelimit-remaining", "12").json_body(serde_json::json!({"message": "Forbidden"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let err = client.get_repo_topics("o", "r").await.unwrap_err();
+    assert!(matches!(err, gh_otco_api::ApiError::RateLimited { status: 403, reset: Some(1_700_000_000) }), "{err:?}");
+    assert_eq!(err.status(), Some(403));
+    // A plain 403 with quota left stays an HTTP error
+    let err = client.get_repo_readme("o", "r", None).await.unwrap_err();
+    assert!(matches!(err, gh_otco_api::ApiError::Http(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn max_items_below_a_page_shrinks_the_request() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/orgs/o/repos").query_param("per_page", "3").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!([{"id": 1}, {"id": 2}, {"id": 3}]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap().with_max_items(Some(3));
+    let repos = client.list_org_repos("o", None, None, None, 100, None).await.unwrap();
+    assert_eq!(repos.len(), 3);
+    m.assert_hits(1);
+    assert_eq!(client.page_info().unwrap().per_page, 3);
+}
+
+#[tokio::test]
+async fn repo_traffic_sends_period() {
+    let server = MockServer::start();
+    let views = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/traffic/views").query_param("per", "week");
+        then.status(200).json_body(serde_json::json!({"count": 3, "uniques": 2, "views": [{"timestamp": "2026-09-28T00:00:00Z", "count": 3, "uniques": 2}]}));
+    });
+    let clones = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/traffic/clones").query_param("per", "week");
+        then.status(200).json_body(serde_json::json!({"count": 1, "uniques": 1, "clones": [{"timestamp": "2026-09-28T00:00:00Z", "count": 1, "uniques": 1}]}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    assert_eq!(client.get_repo_views("o", "r", "week").await.unwrap()["views"][0]["count"], 3);
+    assert_eq!(client.get_repo_clones("o", "r", "week").await.unwrap()["clones"][0]["uniques"], 1);
+    assert!(client.get_repo_views("o", "r", "month").await.is_err());
+    views.assert();
+    clones.assert();
+}
+
+#[tokio::test]
+async fn org_repos_send_server_side_sort() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/orgs/o/repos").query_param("sort", "pushed").query_param("direction", "desc");
+        then.status(200).json_body(serde_json::json!([{"full_name": "o/recent"}]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let repos = client.list_org_repos("o", None, Some("pushed"), Some("desc"), 100, Some(1)).await.unwrap();
+    assert_eq!(repos[0]["full_name"], "o/recent");
+    m.assert();
+    assert!(client.list_org_repos("o", None, Some("stars"), None, 100, Some(1)).await.is_err());
+}
+
+#[tokio::test]
+async fn issue_events_paginates() {
+    let server = MockServer::start();
+    let m1 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/issues/7/events").query_param("per_page", "2").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!([
+            {"event":"labeled","actor":{"login":"octo"},"label":{"name":"bug"}},
+            {"event":"assigned","actor":{"login":"octo"},"assignee":{"login":"cat"}}
+        ]));
+    });
+    let m2 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/issues/7/events").query_param("per_page", "2").query_param("page", "2");
+        then.status(200).json_body(serde_json::json!([{"event":"closed","actor":{"login":"cat"}}]));
+    });
+    let m3 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/issues/7/events").query_param("page", "3");
+        then.status(200).json_body(serde_json::json!([]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let events = client.list_issue_events("o", "r", 7, 2, None).await.unwrap();
+    let kinds: Vec<_> = events.iter().map(|e| e["event"].as_str().unwrap()).collect();
+    assert_eq!(kinds, vec!["labeled", "assigned", "closed"]);
+    assert_eq!(events[1]["assignee"]["login"], "cat");
+    m1.assert();
+    m2.assert();
+    m3.assert();
+}
+
+#[tokio::test]
+async fn stats_count_every_page_request() {
+    let server = MockServer::start();
+    let m1 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/stargazers").query_param("page", "1");
+        then.status(200).header("x-ratelimit-remaining", "4999").json_body(serde_json::json!([{"login":"a"},{"login":"b"}]));
+    });
+    let m2 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/stargazers").query_param("page", "2");
+        then.status(200).header("x-ratelimit-remaining", "4998").json_body(serde_json::json!([{"login":"c"}]));
+    });
+    let m3 = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/stargazers").query_param("page", "3");
+        then.status(200).header("x-ratelimit-remaining", "4997").json_body(serde_json::json!([]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    assert_eq!(client.stats(), gh_otco_api::RequestStats::default());
+    let users = client.clone().list_repo_stargazers("o", "r", 2, None).await.unwrap();
+    let stats = client.stats();
+    assert_eq!(stats.requests, 3);
+    assert_eq!(stats.requests, client.page_info().unwrap().pages_fetched);
+    assert_eq!(stats.items, users.len() as u64);
+    assert_eq!(stats.rate_limit_remaining, Some(4997));
+    m1.assert();
+    m2.assert();
+    m3.assert();
+}
+
+#[tokio::test]
+async fn workflow_runs_send_event_actor_and_created() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET)
+            .path("/repos/o/r/actions/runs")
+            .query_param("event", "pull_request")
+            .query_param("actor", "octo")
+            .query_param("created", ">=2024-05-01");
+        then.status(200).json_body(serde_json::json!({"total_count": 1, "workflow_runs": [{"id": 9, "event": "pull_request"}]}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let runs = client
+        .list_repo_workflow_runs("o", "r", None, None, None, Some("pull_request"), Some("octo"), Some(">=2024-05-01"), 100, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(runs[0]["id"], 9);
+    m.assert();
+}
+
+#[tokio::test]
+async fn pulls_send_head_sort_and_direction() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET)
+            .path("/repos/o/r/pulls")
+            .query_param("head", "o:feature")
+            .query_param("sort", "long-running")
+            .query_param("direction", "asc");
+        then.status(200).json_body(serde_json::json!([{"number": 3}]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    // A bare branch is qualified with the repository owner
+    let prs = client.list_repo_pulls("o", "r", None, None, None, Some("feature"), Some("long-running"), Some("asc"), 100, Some(1)).await.unwrap();
+    assert_eq!(prs[0]["number"], 3);
+    m.assert();
+    assert!(client.list_repo_pulls("o", "r", None, None, None, None, Some("stars"), None, 100, Some(1)).await.is_err());
+}
+
+#[tokio::test]
+async fn pulls_merged_state_filters_closed_pulls() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/pulls").query_param("state", "closed");
+        then.status(200).json_body(serde_json::json!([
+            {"number": 1, "merged_at": "2024-05-01T10:00:00Z"},
+            {"number": 2, "merged_at": null},
+            {"number": 3, "merged_at": "2024-05-02T10:00:00Z"}
+        ]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let prs = client.list_repo_pulls("o", "r", Some("merged"), None, None, None, None, None, 100, Some(1)).await.unwrap();
+    let numbers: Vec<_> = prs.iter().map(|p| p["number"].as_u64().unwrap()).collect();
+    assert_eq!(numbers, vec![1, 3]);
+    m.assert();
+}
+
+#[tokio::test]
+async fn page_sink_receives_each_page() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/stargazers").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!([{"login":"a"},{"login":"b"}]));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/stargazers").query_param("page", "2");
+        then.status(200).json_body(serde_json::json!([{"login":"c"},{"login":"d"}]));
+    });
+    let pages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen = pages.clone();
+    let sink: gh_otco_api::PageSink = std::sync::Arc::new(move |items: &[serde_json::Value]| seen.lock().unwrap().push(items.len()));
+    // With keep = false nothing is collected; max_items still cuts the last page
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap().with_max_items(Some(3)).with_page_sink(sink, false);
+    let users = client.list_repo_stargazers("o", "r", 2, None).await.unwrap();
+    assert!(users.is_empty());
+    assert_eq!(*pages.lock().unwrap(), vec![2, 1]);
+    assert_eq!(client.stats().items, 3);
+}
+
+#[tokio::test]
+async fn branch_protection_returns_rules() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/branches/main/protection");
+        then.status(200).json_body(serde_json::json!({
+            "required_status_checks": {"strict": true, "contexts": ["ci/build"]},
+            "required_pull_request_reviews": {"required_approving_review_count": 2},
+            "enforce_admins": {"enabled": true}
+        }));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let rules = client.get_branch_protection("o", "r", "main").await.unwrap();
+    assert_eq!(rules["required_pull_request_reviews"]["required_approving_review_count"], 2);
+    assert_eq!(rules["enforce_admins"]["enabled"], true);
+    m.assert();
+}
+
+#[tokio::test]
+async fn branch_protection_tells_unprotected_from_missing() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/branches/dev/protection");
+        then.status(404).json_body(serde_json::json!({"message": "Branch not protected"}));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/branches/gone/protection");
+        then.status(404).json_body(serde_json::json!({"message": "Branch not found"}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let err = client.get_branch_protection("o", "r", "dev").await.unwrap_err();
+    assert!(matches!(&err, gh_otco_api::ApiError::BranchNotProtected { branch } if branch == "dev"), "{err:?}");
+    let err = client.get_branch_protection("o", "r", "gone").await.unwrap_err();
+    assert!(matches!(err, gh_otco_api::ApiError::Http(_)), "{err:?}");
+    assert_eq!(err.status(), Some(404));
+}
+
+#[tokio::test]
+async fn workflow_run_usage_returns_timing() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/repos/o/r/actions/runs/42/timing");
+        then.status(200).json_body(serde_json::json!({"billable": {"UBUNTU": {"total_ms": 180000, "jobs": 2}}, "run_duration_ms": 95000}));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let usage = client.get_workflow_run_usage("o", "r", 42).await.unwrap();
+    assert_eq!(usage["billable"]["UBUNTU"]["total_ms"], 180000);
+    assert_eq!(usage["run_duration_ms"], 95000);
+    m.assert();
+}
+
+#[tokio::test]
+async fn list_repo_issues_sends_creator_mentioned_and_order() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET)
+            .path("/repos/o/r/issues")
+            .query_param("creator", "octocat")
+            .query_param("mentioned", "hubot")
+            .query_param("sort", "comments")
+            .query_param("direction", "asc");
+        then.status(200).json_body(serde_json::json!([{"number": 1}]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let issues = client
+        .list_repo_issues("o", "r", None, None, None, Some("octocat"), Some("hubot"), None, None, Some("comments"), Some("asc"), 100, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(issues.len(), 1);
+    m.assert();
+    let err = client
+        .list_repo_issues("o", "r", None, None, None, None, None, None, None, Some("popularity"), None, 100, Some(1))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("created, updated, comments"), "{err}");
+}
+
+#[tokio::test]
+async fn set_workflow_state_puts_enable_and_disable() {
+    let server = MockServer::start();
+    let disable = server.mock(|when, then| {
+        when.method(PUT).path("/repos/o/r/actions/workflows/nightly.yml/disable");
+        then.status(204);
+    });
+    let enable = server.mock(|when, then| {
+        when.method(PUT).path("/repos/o/r/actions/workflows/161335/enable");
+        then.status(204);
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    client.set_workflow_state("o", "r", "nightly.yml", false).await.unwrap();
+    client.set_workflow_state("o", "r", "161335", true).await.unwrap();
+    disable.assert();
+    enable.assert();
+}
+
+#[tokio::test]
+async fn current_user_extended_fetches_only_what_is_asked() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/user");
+        then.status(200).json_body(serde_json::json!({"login": "octo", "id": 1, "name": "Octo Cat", "company": "@github", "html_url": "https://github.com/octo"}));
+    });
+    let emails = server.mock(|when, then| {
+        when.method(GET).path("/user/emails");
+        then.status(200).json_body(serde_json::json!([]));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/user/orgs").query_param("page", "1");
+        then.status(200).json_body(serde_json::json!([{"login": "acme"}, {"login": "octo-labs"}]));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/user/orgs").query_param("page", "2");
+        then.status(200).json_body(serde_json::json!([]));
+    });
+    let client = GitHubClient::new(Some(server.url("")), None).unwrap();
+    let profile = client.current_user_extended(false, false).await.unwrap();
+    assert_eq!(profile.user.name.as_deref(), Some("Octo Cat"));
+    assert_eq!(profile.user.company.as_deref(), Some("@github"));
+    assert!(profile.emails.is_none() && profile.orgs.is_none());
+    let profile = client.current_user_extended(false, true).await.unwrap();
+    assert_eq!(profile.orgs.unwrap(), ["acme", "octo-labs"]);
+    emails.assert_hits(0);
+}
+
+#[tokio::test]
+async fn explain_mode_records_every_independent_request() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET);
+        then.status(200).json_body(serde_json::json!({}));
+    });
+    let client = GitHubClient::new(Some(server.url("").to_string()), Some("testtoken".into()))
+        .unwrap()
+        .with_explain(true);
+    let err = client.clone().current_user_extended(true, true).await.unwrap_err();
+    assert!(matches!(err, gh_otco_api::ApiError::Explain(_)));
+    let urls: Vec<String> = client.take_plans().into_iter().map(|p| p.url).collect();
+    assert_eq!(urls.len(), 3, "{urls:?}");
+    assert!(urls[0].ends_with("/user"));
+    assert!(urls[1].contains("/user/emails?"));
+    assert!(urls[2].contains("/user/orgs?"));
+    assert!(client.take_plans().is_empty());
+    m.assert_hits(0);
+}
+
+fn temp_cache_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("otco-cache-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[tokio::test]
+async fn cache_entries_are_per_token_and_private() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/orgs/myorg/repos");
+        then.status(200).json_body(serde_json::json!([{"name": "private-repo"}]));
+    });
+    let dir = temp_cache_dir("per-token");
+    let cache = gh_otco_api::ResponseCache::new(dir.clone(), std::time::Duration::from_secs(300));
+    let client = |token: Option<&str>| {
+        GitHubClient::new(Some(server.url("")), token.map(str::to_string)).unwrap().with_cache(cache.clone())
+    };
+    client(Some("token-a")).list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    client(Some("token-a")).list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    client(Some("token-b")).list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    client(None).list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    m.assert_hits(3);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(std::fs::metadata(&dir).unwrap().permissions().mode() & 0o777, 0o700);
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let entry = entry.unwrap();
+            assert_eq!(entry.metadata().unwrap().permissions().mode() & 0o777, 0o600);
+            assert!(!std::fs::read_to_string(entry.path()).unwrap().contains("token-a"));
+        }
+    }
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn cache_hit_skips_network() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/orgs/myorg/repos");
+        then.status(200).json_body(serde_json::json!([{"name":"a"}]));
+    });
+    let dir = temp_cache_dir("hit");
+    let cache = gh_otco_api::ResponseCache::new(dir.clone(), std::time::Duration::from_secs(300));
+    let client = GitHubClient::new(Some(server.url("").to_string()), None)
+        .unwrap()
+        .with_cache(cache.clone());
+    let first = client.list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    m.assert_hits(1);
+
+    // A fresh client (as in a second CLI invocation) reads the stored body
+    let client = GitHubClient::new(Some(server.url("").to_string()), None)
+        .unwrap()
+        .with_cache(cache);
+    let second = client.list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    assert_eq!(first, second);
+    m.assert_hits(1);
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn cache_miss_after_ttl_and_on_refresh() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.method(GET).path("/orgs/myorg/repos");
+        then.status(200).json_body(serde_json::json!([{"name":"a"}]));
+    });
+    let dir = temp_cache_dir("ttl");
+    let cache = gh_otco_api::ResponseCache::new(dir.clone(), std::time::Duration::from_millis(50));
+    let client = GitHubClient::new(Some(server.url("").to_string()), None)
+        .unwrap()
+        .with_cache(cache.clone());
+    client.list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    client.list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    m.assert_hits(2);
+
+    let client = GitHubClient::new(Some(server.url("").to_string()), None)
+        .unwrap()
+        .with_cache(gh_otco_api::ResponseCache::new(dir.clone(), std::time::Duration::from_secs(300)).refresh(true));
+    client.list_org_repos("myorg", None, None, None, 100, Some(1)).await.unwrap();
+    m.assert_hits(3);
+    let _ = std::fs::remove_dir_all(dir);
+}
@@ -0,0 +1,334 @@
+//! File exporters for analytics targets. Parquet and SQLite are behind their
+//! own cargo features so the default build stays small; the Prometheus
+//! textfile writer needs no extra dependencies.
+
+use crate::Row;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Column types inferred from normalized (string) cells. Empty cells are nulls
+/// and do not influence the inferred type.
+#[cfg_attr(not(any(feature = "parquet", feature = "sqlite")), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ColumnType {
+    Integer,
+    Real,
+    Boolean,
+    Timestamp,
+    Text,
+}
+
+#[cfg_attr(not(any(feature = "parquet", feature = "sqlite")), allow(dead_code))]
+pub(crate) fn infer_column_type<'a>(cells: impl Iterator<Item = &'a str>) -> ColumnType {
+    let mut ty: Option<ColumnType> = None;
+    for cell in cells.filter(|c| !c.is_empty()) {
+        let this = if cell.parse::<i64>().is_ok() {
+            ColumnType::Integer
+        } else if cell.parse::<f64>().is_ok_and(f64::is_finite) {
+            ColumnType::Real
+        } else if cell == "true" || cell == "false" {
+            ColumnType::Boolean
+        } else if chrono::DateTime::parse_from_rfc3339(cell).is_ok() {
+            ColumnType::Timestamp
+        } else {
+            ColumnType::Text
+        };
+        ty = match ty {
+            None => Some(this),
+            Some(t) if t == this => Some(t),
+            // Integers widen to reals rather than falling back to text
+            Some(ColumnType::Integer | ColumnType::Real) if matches!(this, ColumnType::Integer | ColumnType::Real) => Some(ColumnType::Real),
+            Some(_) => return ColumnType::Text,
+        };
+    }
+    ty.unwrap_or(ColumnType::Text)
+}
+
+// Nested objects/arrays are rendered as JSON text by `render_value`; refuse to
+// store them so the file keeps a flat, typed schema.
+#[cfg_attr(not(feature = "parquet"), allow(dead_code))]
+fn is_nested(cell: &str) -> bool {
+    (cell.starts_with('{') || cell.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(cell).is_ok_and(|v| v.is_object() || v.is_array())
+}
+
+#[cfg(feature = "parquet")]
+pub(crate) fn write_parquet(columns: &[String], rows: &[Row], path: &Path) -> Result<()> {
+    use arrow_array::{ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray, TimestampMillisecondArray};
+    use arrow_schema::{DataType, Field, Schema, TimeUnit};
+    use std::sync::Arc;
+
+    fn cell<'a>(row: &'a Row, c: &str) -> &'a str {
+        row.get(c).map(|s| s.as_str()).unwrap_or_default()
+    }
+    let mut fields = Vec::new();
+    let mut arrays: Vec<ArrayRef> = Vec::new();
+    for c in columns {
+        if let Some(bad) = rows.iter().map(|r| cell(r, c)).find(|v| is_nested(v)) {
+            anyhow::bail!(
+                "column '{c}' holds nested data ({}) that parquet output cannot flatten; select scalar fields with --fields (e.g. {c}.id)",
+                bad.chars().take(40).collect::<String>()
+            );
+        }
+        let values = || rows.iter().map(|r| Some(cell(r, c)).filter(|v| !v.is_empty()));
+        let (dt, array): (DataType, ArrayRef) = match infer_column_type(rows.iter().map(|r| cell(r, c))) {
+            ColumnType::Integer => (
+                DataType::Int64,
+                Arc::new(Int64Array::from(values().map(|v| v.and_then(|s| s.parse().ok())).collect::<Vec<Option<i64>>>())),
+            ),
+            ColumnType::Real => (
+                DataType::Float64,
+                Arc::new(Float64Array::from(values().map(|v| v.and_then(|s| s.parse().ok())).collect::<Vec<Option<f64>>>())),
+            ),
+            ColumnType::Boolean => (
+                DataType::Boolean,
+                Arc::new(BooleanArray::from(values().map(|v| v.map(|s| s == "true")).collect::<Vec<Option<bool>>>())),
+            ),
+            ColumnType::Timestamp => {
+                let millis = values()
+                    .map(|v| v.and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok()).map(|t| t.timestamp_millis()))
+                    .collect::<Vec<Option<i64>>>();
+                (
+                    DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                    Arc::new(TimestampMillisecondArray::from(millis).with_timezone("UTC")),
+                )
+            }
+            ColumnType::Text => (DataType::Utf8, Arc::new(StringArray::from(values().collect::<Vec<Option<&str>>>()))),
+        };
+        fields.push(Field::new(c, dt, true));
+        arrays.push(array);
+    }
+    let schema = Arc::new(Schema::new(fields));
+    let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+    let file = std::fs::File::create(path)?;
+    let mut writer = parquet::arrow::ArrowWriter::try_new(file, schema, None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(not(feature = "parquet"))]
+pub(crate) fn write_parquet(_columns: &[String], _rows: &[crate::Row], _path: &Path) -> Result<()> {
+    anyhow::bail!("parquet output is not available in this build; rebuild with `--features parquet`")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, clap::ValueEnum)]
+pub(crate) enum SqliteMode {
+    Create,
+    Append,
+}
+
+#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
+#[derive(Debug, Clone)]
+pub(crate) struct SqliteTarget {
+    pub path: PathBuf,
+    pub table: String,
+    pub mode: SqliteMode,
+}
+
+#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Load rows into `target.table`, typing columns INTEGER/REAL when every
+/// non-empty value is numeric and TEXT otherwise. Returns the rows inserted.
+#[cfg(feature = "sqlite")]
+pub(crate) fn write_sqlite(columns: &[String], rows: &[Row], target: &SqliteTarget) -> Result<usize> {
+    use rusqlite::types::Value as Sql;
+
+    let mut conn = rusqlite::Connection::open(&target.path)?;
+    let table = quote_ident(&target.table);
+    let types: Vec<ColumnType> = columns
+        .iter()
+        .map(|c| infer_column_type(rows.iter().map(|r| r.get(c).map(|s| s.as_str()).unwrap_or_default())))
+        .collect();
+    let defs: Vec<String> = columns
+        .iter()
+        .zip(&types)
+        .map(|(c, t)| {
+            let sql_type = match t { ColumnType::Integer => "INTEGER", ColumnType::Real => "REAL", _ => "TEXT" };
+            format!("{} {sql_type}", quote_ident(c))
+        })
+        .collect();
+    let tx = conn.transaction()?;
+    if target.mode == SqliteMode::Create {
+        tx.execute(&format!("DROP TABLE IF EXISTS {table}"), [])?;
+    }
+    if columns.is_empty() {
+        tx.commit()?;
+        return Ok(0);
+    }
+    tx.execute(&format!("CREATE TABLE IF NOT EXISTS {table} ({})", defs.join(", ")), [])?;
+    {
+        let names: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+        let marks = vec!["?"; columns.len()].join(", ");
+        let mut stmt = tx.prepare(&format!("INSERT INTO {table} ({}) VALUES ({marks})", names.join(", ")))?;
+        for row in rows {
+            let values = columns.iter().zip(&types).map(|(c, t)| match row.get(c).map(|s| s.as_str()).unwrap_or_default() {
+                "" => Sql::Null,
+                v => match t {
+                    ColumnType::Integer => v.parse().map(Sql::Integer).unwrap_or_else(|_| Sql::Text(v.into())),
+                    ColumnType::Real => v.parse().map(Sql::Real).unwrap_or_else(|_| Sql::Text(v.into())),
+                    _ => Sql::Text(v.into()),
+                },
+            });
+            stmt.execute(rusqlite::params_from_iter(values))?;
+        }
+    }
+    tx.commit()?;
+    Ok(rows.len())
+}
+
+#[cfg(not(feature = "sqlite"))]
+pub(crate) fn write_sqlite(_columns: &[String], _rows: &[Row], _target: &SqliteTarget) -> Result<usize> {
+    anyhow::bail!("--sqlite is not available in this build; rebuild with `--features sqlite`")
+}
+
+/// One Prometheus gauge sample. Samples sharing a name are grouped under one
+/// HELP/TYPE header, in first-seen order.
+#[derive(Debug, Clone)]
+pub(crate) struct Gauge {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub labels: Vec<(&'static str, String)>,
+    pub value: f64,
+}
+
+/// Render gauges in the Prometheus text exposition format.
+pub(crate) fn prometheus_text(gauges: &[Gauge]) -> String {
+    let mut names: Vec<&str> = Vec::new();
+    for g in gauges {
+        if !names.contains(&g.name) {
+            names.push(g.name);
+        }
+    }
+    let mut out = String::new();
+    for name in names {
+        let samples: Vec<&Gauge> = gauges.iter().filter(|g| g.name == name).collect();
+        out.push_str(&format!("# HELP {name} {}\n# TYPE {name} gauge\n", samples[0].help));
+        for g in samples {
+            let labels: Vec<String> = g.labels.iter().map(|(k, v)| format!("{k}=\"{}\"", escape_label(v))).collect();
+            let labels = if labels.is_empty() { String::new() } else { format!("{{{}}}", labels.join(",")) };
+            out.push_str(&format!("{name}{labels} {}\n", g.value));
+        }
+    }
+    out
+}
+
+fn escape_label(v: &str) -> String {
+    v.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Replace `path` atomically: write a temp file beside it, then rename, so a
+/// textfile collector never reads a half-written file.
+pub(crate) fn write_textfile(contents: &str, path: &Path) -> Result<()> {
+    use anyhow::Context;
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let tmp = path.with_file_name(format!(".{name}.{}.tmp", std::process::id()));
+    std::fs::write(&tmp, contents).with_context(|| format!("writing {}", tmp.display()))?;
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    }).with_context(|| format!("replacing {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infers_column_types_from_cells() {
+        assert_eq!(infer_column_type(["1", "", "42"].into_iter()), ColumnType::Integer);
+        assert_eq!(infer_column_type(["true", "false"].into_iter()), ColumnType::Boolean);
+        assert_eq!(infer_column_type(["2024-01-01T00:00:00Z"].into_iter()), ColumnType::Timestamp);
+        assert_eq!(infer_column_type(["1", "x"].into_iter()), ColumnType::Text);
+        assert_eq!(infer_column_type(["1", "2.5"].into_iter()), ColumnType::Real);
+        assert!(is_nested(r#"{"login":"octo"}"#));
+        assert!(!is_nested("[WIP] fix"));
+    }
+
+    #[test]
+    fn prometheus_text_groups_samples_and_escapes_labels() {
+        let gauge = |name, labels: Vec<(&'static str, String)>, value| Gauge { name, help: "help text", labels, value };
+        let text = prometheus_text(&[
+            gauge("github_open_issues", vec![("repo", "o/a".into())], 12.0),
+            gauge("github_rate_limit_remaining", vec![], 4873.0),
+            gauge("github_open_issues", vec![("repo", "o/\"b\"".into())], 0.0),
+        ]);
+        assert_eq!(
+            text,
+            "# HELP github_open_issues help text\n# TYPE github_open_issues gauge\n\
+             github_open_issues{repo=\"o/a\"} 12\ngithub_open_issues{repo=\"o/\\\"b\\\"\"} 0\n\
+             # HELP github_rate_limit_remaining help text\n# TYPE github_rate_limit_remaining gauge\n\
+             github_rate_limit_remaining 4873\n"
+        );
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn parquet_round_trip() {
+        use arrow_array::{Array, Int64Array, StringArray};
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let columns: Vec<String> = vec!["number".into(), "title".into(), "created_at".into()];
+        let rows = vec![
+            Row::from([("number".into(), "1".into()), ("title".into(), "First".into()), ("created_at".into(), "2024-01-01T00:00:00Z".into())]),
+            Row::from([("number".into(), "2".into()), ("title".into(), "Second".into()), ("created_at".into(), "".into())]),
+        ];
+        let path = std::env::temp_dir().join(format!("otco-export-{}.parquet", std::process::id()));
+        write_parquet(&columns, &rows, &path).unwrap();
+
+        let file = std::fs::File::open(&path).unwrap();
+        let batches: Vec<_> = ParquetRecordBatchReaderBuilder::try_new(file).unwrap().build().unwrap().collect::<Result<_, _>>().unwrap();
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 2);
+        let numbers = batch.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(numbers.value(1), 2);
+        let titles = batch.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(titles.value(0), "First");
+        assert!(batch.column(2).is_null(1));
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_creates_and_appends() {
+        let columns: Vec<String> = vec!["number".into(), "title".into(), "user.login".into()];
+        let rows = vec![
+            Row::from([("number".into(), "1".into()), ("title".into(), "First".into()), ("user.login".into(), "octo".into())]),
+            Row::from([("number".into(), "2".into()), ("title".into(), "Second".into()), ("user.login".into(), "".into())]),
+        ];
+        let path = std::env::temp_dir().join(format!("otco-export-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut target = SqliteTarget { path: path.clone(), table: "issues".into(), mode: SqliteMode::Create };
+        assert_eq!(write_sqlite(&columns, &rows, &target).unwrap(), 2);
+        target.mode = SqliteMode::Append;
+        write_sqlite(&columns, &rows, &target).unwrap();
+
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM issues", [], |r| r.get(0)).unwrap();
+        assert_eq!(count, 4);
+        let total: i64 = conn.query_row("SELECT SUM(number) FROM issues", [], |r| r.get(0)).unwrap();
+        assert_eq!(total, 6);
+        let nulls: i64 = conn.query_row(r#"SELECT COUNT(*) FROM issues WHERE "user.login" IS NULL"#, [], |r| r.get(0)).unwrap();
+        assert_eq!(nulls, 2);
+        drop(conn);
+
+        target.mode = SqliteMode::Create;
+        write_sqlite(&columns, &rows[..1], &target).unwrap();
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM issues", [], |r| r.get(0)).unwrap();
+        assert_eq!(count, 1);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn parquet_rejects_nested_columns() {
+        let columns: Vec<String> = vec!["user".into()];
+        let rows = vec![Row::from([("user".into(), r#"{"login":"octo"}"#.into())])];
+        let path = std::env::temp_dir().join(format!("otco-nested-{}.parquet", std::process::id()));
+        let err = write_parquet(&columns, &rows, &path).unwrap_err();
+        assert!(err.to_string().contains("--fields"));
+    }
+}
@@ -0,0 +1,292 @@
+//! Interactive result browser (`--interactive`). The key handling is a plain
+//! reducer over [`BrowserState`] so it can be tested without a terminal; the
+//! ratatui loop only draws the state and feeds it [`Action`]s.
+
+use crate::Row;
+use anyhow::Result;
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Paragraph, Row as TableRow, Table, TableState},
+    DefaultTerminal, Frame,
+};
+
+const PAGE: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Mode {
+    List,
+    Filter,
+    Detail,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Action {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    StartFilter,
+    FilterChar(char),
+    FilterBackspace,
+    FilterCommit,
+    FilterCancel,
+    Expand,
+    Back,
+    Quit,
+}
+
+/// Selection, filter, and view mode for one browsing session. `selected` indexes
+/// the filtered view, not `rows`.
+#[derive(Debug, Clone)]
+pub(crate) struct BrowserState {
+    rows: Vec<Row>,
+    pub(crate) filter: String,
+    pub(crate) mode: Mode,
+    pub(crate) selected: usize,
+    pub(crate) detail_scroll: u16,
+    pub(crate) quit: bool,
+    visible: Vec<usize>,
+}
+
+impl BrowserState {
+    pub(crate) fn new(rows: Vec<Row>) -> Self {
+        let visible = (0..rows.len()).collect();
+        Self { rows, filter: String::new(), mode: Mode::List, selected: 0, detail_scroll: 0, quit: false, visible }
+    }
+
+    /// Row indices (into the original rows) that match the current filter.
+    pub(crate) fn visible(&self) -> &[usize] {
+        &self.visible
+    }
+
+    /// Original index of the highlighted row, if the filtered view is not empty.
+    pub(crate) fn current(&self) -> Option<usize> {
+        self.visible.get(self.selected).copied()
+    }
+
+    pub(crate) fn apply(&mut self, action: Action) {
+        let last = self.visible.len().saturating_sub(1);
+        match (self.mode, action) {
+            (_, Action::Quit) => self.quit = true,
+            (Mode::Detail, Action::Up) => self.detail_scroll = self.detail_scroll.saturating_sub(1),
+            (Mode::Detail, Action::Down) => self.detail_scroll = self.detail_scroll.saturating_add(1),
+            (Mode::Detail, Action::PageUp) => self.detail_scroll = self.detail_scroll.saturating_sub(PAGE as u16),
+            (Mode::Detail, Action::PageDown) => self.detail_scroll = self.detail_scroll.saturating_add(PAGE as u16),
+            (Mode::Detail, Action::Back) => self.mode = Mode::List,
+            (Mode::List, Action::Back) if !self.filter.is_empty() => self.set_filter(String::new()),
+            (Mode::List, Action::Back) => self.quit = true,
+            (Mode::List | Mode::Filter, Action::Up) => self.selected = self.selected.saturating_sub(1),
+            (Mode::List | Mode::Filter, Action::Down) => self.selected = (self.selected + 1).min(last),
+            (Mode::List, Action::PageUp) => self.selected = self.selected.saturating_sub(PAGE),
+            (Mode::List, Action::PageDown) => self.selected = (self.selected + PAGE).min(last),
+            (Mode::List, Action::Top) => self.selected = 0,
+            (Mode::List, Action::Bottom) => self.selected = last,
+            (Mode::List, Action::StartFilter) => self.mode = Mode::Filter,
+            (Mode::List | Mode::Filter, Action::Expand) if self.current().is_some() => {
+                self.mode = Mode::Detail;
+                self.detail_scroll = 0;
+            }
+            (Mode::Filter, Action::FilterChar(c)) => {
+                let mut f = self.filter.clone();
+                f.push(c);
+                self.set_filter(f);
+            }
+            (Mode::Filter, Action::FilterBackspace) => {
+                let mut f = self.filter.clone();
+                f.pop();
+                self.set_filter(f);
+            }
+            (Mode::Filter, Action::FilterCommit) => self.mode = Mode::List,
+            (Mode::Filter, Action::FilterCancel) => {
+                self.set_filter(String::new());
+                self.mode = Mode::List;
+            }
+            _ => {}
+        }
+    }
+
+    // Case-insensitive substring match against any cell; keeps the highlighted
+    // row selected when it survives the new filter.
+    fn set_filter(&mut self, filter: String) {
+        let keep = self.current();
+        let needle = filter.to_lowercase();
+        self.visible = (0..self.rows.len())
+            .filter(|&i| needle.is_empty() || self.rows[i].values().any(|cell| cell.to_lowercase().contains(&needle)))
+            .collect();
+        self.selected = keep.and_then(|k| self.visible.iter().position(|&i| i == k)).unwrap_or(0);
+        self.filter = filter;
+    }
+}
+
+/// Map a key press to an action for the given mode; unmapped keys are ignored.
+pub(crate) fn action_for(mode: Mode, key: KeyEvent) -> Option<Action> {
+    if key.kind != KeyEventKind::Press {
+        return None;
+    }
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        return Some(Action::Quit);
+    }
+    Some(match (mode, key.code) {
+        (_, KeyCode::Up) => Action::Up,
+        (_, KeyCode::Down) => Action::Down,
+        (Mode::Filter, KeyCode::Enter) => Action::FilterCommit,
+        (Mode::Filter, KeyCode::Esc) => Action::FilterCancel,
+        (Mode::Filter, KeyCode::Backspace) => Action::FilterBackspace,
+        (Mode::Filter, KeyCode::Char(c)) => Action::FilterChar(c),
+        (_, KeyCode::PageUp) => Action::PageUp,
+        (_, KeyCode::PageDown) => Action::PageDown,
+        (_, KeyCode::Home) => Action::Top,
+        (_, KeyCode::End) => Action::Bottom,
+        (_, KeyCode::Char('k')) => Action::Up,
+        (_, KeyCode::Char('j')) => Action::Down,
+        (Mode::List, KeyCode::Char('/')) => Action::StartFilter,
+        (Mode::List, KeyCode::Enter) => Action::Expand,
+        (_, KeyCode::Esc | KeyCode::Backspace) => Action::Back,
+        (_, KeyCode::Char('q')) => Action::Quit,
+        _ => return None,
+    })
+}
+
+/// Browse projected rows until the user quits; Enter shows `records[i]` for row `i`.
+pub(crate) fn browse(columns: &[String], rows: Vec<Row>, records: &[serde_json::Value]) -> Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, columns, BrowserState::new(rows), records);
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal, columns: &[String], mut state: BrowserState, records: &[serde_json::Value]) -> Result<()> {
+    let mut table = TableState::default();
+    while !state.quit {
+        terminal.draw(|f| draw(f, columns, &state, records, &mut table))?;
+        if let Event::Key(key) = event::read()? {
+            if let Some(action) = action_for(state.mode, key) {
+                state.apply(action);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn draw(f: &mut Frame, columns: &[String], state: &BrowserState, records: &[serde_json::Value], table: &mut TableState) {
+    let [body, footer] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(f.area());
+    let status = match state.mode {
+        Mode::Filter => format!("/{}", state.filter),
+        Mode::Detail => "↑/↓ scroll · Esc back · q quit".to_string(),
+        Mode::List => {
+            let filter = if state.filter.is_empty() { String::new() } else { format!(" · filter: {}", state.filter) };
+            format!("{}/{} rows{filter} · ↑/↓ move · / filter · Enter expand · q quit", state.visible().len(), state.rows.len())
+        }
+    };
+    f.render_widget(Paragraph::new(Line::from(status)), footer);
+
+    if state.mode == Mode::Detail {
+        let record = state.current().and_then(|i| records.get(i));
+        let text = record.and_then(|r| serde_json::to_string_pretty(r).ok()).unwrap_or_default();
+        let detail = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("record")).scroll((state.detail_scroll, 0));
+        f.render_widget(detail, body);
+        return;
+    }
+
+    let header = TableRow::new(columns.iter().map(|c| Cell::from(c.as_str()))).style(Style::default().add_modifier(Modifier::BOLD));
+    let rows = state.visible().iter().map(|&i| {
+        let row = &state.rows[i];
+        TableRow::new(columns.iter().map(|c| Cell::from(row.get(c).map(String::as_str).unwrap_or_default())))
+    });
+    let widths = columns.iter().map(|_| Constraint::Fill(1));
+    let view = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL))
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    table.select((!state.visible().is_empty()).then_some(state.selected));
+    f.render_stateful_widget(view, body, table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Row> {
+        ["alpha", "beta", "gamma", "alphabet"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Row::from([("number".to_string(), (i + 1).to_string()), ("title".to_string(), name.to_string())]))
+            .collect()
+    }
+
+    fn typing(state: &mut BrowserState, s: &str) {
+        s.chars().for_each(|c| state.apply(Action::FilterChar(c)));
+    }
+
+    #[test]
+    fn reducer_filters_and_tracks_selection() {
+        let mut s = BrowserState::new(rows());
+        s.apply(Action::Up);
+        assert_eq!(s.current(), Some(0));
+        s.apply(Action::PageDown);
+        assert_eq!(s.current(), Some(3), "selection clamps to the last row");
+
+        // Filtering keeps the highlighted row when it still matches
+        s.apply(Action::StartFilter);
+        assert_eq!(s.mode, Mode::Filter);
+        typing(&mut s, "ALPHA");
+        assert_eq!(s.visible(), &[0, 3]);
+        assert_eq!(s.current(), Some(3));
+        s.apply(Action::FilterBackspace);
+        typing(&mut s, "AB");
+        assert_eq!(s.visible(), &[3]);
+        s.apply(Action::FilterCommit);
+        assert_eq!(s.mode, Mode::List);
+
+        // Filters match any cell; a dropped selection resets to the top
+        s.apply(Action::StartFilter);
+        (0..6).for_each(|_| s.apply(Action::FilterBackspace));
+        typing(&mut s, "2");
+        assert_eq!(s.visible(), &[1]);
+        assert_eq!(s.selected, 0);
+        s.apply(Action::FilterCancel);
+        assert_eq!(s.visible().len(), 4);
+
+        // No match: Enter does nothing
+        s.apply(Action::StartFilter);
+        typing(&mut s, "zzz");
+        s.apply(Action::Expand);
+        assert_eq!(s.mode, Mode::Filter);
+        assert_eq!(s.current(), None);
+        s.apply(Action::FilterCancel);
+
+        // Expand and back; Esc in the list clears a filter before quitting
+        s.apply(Action::Down);
+        s.apply(Action::Expand);
+        assert_eq!((s.mode, s.current()), (Mode::Detail, Some(1)));
+        s.apply(Action::Down);
+        assert_eq!(s.detail_scroll, 1);
+        s.apply(Action::Back);
+        assert_eq!(s.mode, Mode::List);
+        s.apply(Action::StartFilter);
+        typing(&mut s, "beta");
+        s.apply(Action::FilterCommit);
+        s.apply(Action::Back);
+        assert!(s.filter.is_empty() && !s.quit);
+        s.apply(Action::Back);
+        assert!(s.quit);
+    }
+
+    #[test]
+    fn keys_map_per_mode() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert_eq!(action_for(Mode::List, key(KeyCode::Char('/'))), Some(Action::StartFilter));
+        assert_eq!(action_for(Mode::Filter, key(KeyCode::Char('/'))), Some(Action::FilterChar('/')));
+        assert_eq!(action_for(Mode::Filter, key(KeyCode::Char('q'))), Some(Action::FilterChar('q')));
+        assert_eq!(action_for(Mode::List, key(KeyCode::Char('q'))), Some(Action::Quit));
+        assert_eq!(action_for(Mode::List, key(KeyCode::Enter)), Some(Action::Expand));
+        assert_eq!(action_for(Mode::Filter, key(KeyCode::Enter)), Some(Action::FilterCommit));
+        assert_eq!(action_for(Mode::Detail, key(KeyCode::Esc)), Some(Action::Back));
+        assert_eq!(action_for(Mode::Filter, KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)), Some(Action::Quit));
+    }
+}